@@ -2,3 +2,51 @@ use anchor_lang::prelude::*;
 
 #[constant]
 pub const SEED: &str = "anchor";
+
+/// The denominator of every amount expressed in basis points
+#[constant]
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// The max platform fee that can be taken on the raised tokens, i.e., 10%
+#[constant]
+pub const MAX_RAISED_TOKEN_FEE_BPS: u16 = 1_000;
+
+/// The max platform fee that can be taken on the launched tokens, i.e., 10%
+#[constant]
+pub const MAX_LAUNCHED_TOKEN_FEE_BPS: u16 = 1_000;
+
+/// The max part of the platform fee that can be paid to the referrers, i.e., 100%
+#[constant]
+pub const MAX_REFERRAL_FEE_BPS: u16 = 10_000;
+
+/// The lamports held by the vault authority of an lbp to pay the fees and rents of the pool creation
+#[constant]
+pub const POOL_CREATION_FUNDING_LAMPORTS: u64 = 500_000_000;
+
+/// The denominator of the unlocked fractions returned by the vesting schedules
+#[constant]
+pub const UNLOCKED_FRACTION_DENOMINATOR: u64 = 1_000_000_000;
+
+/// The max number of unlock points of a custom vesting schedule
+#[constant]
+pub const MAX_UNLOCK_POINTS: u8 = 12;
+
+/// The max number of members of the admin multisig
+#[constant]
+pub const MAX_ADMIN_MEMBERS: u8 = 10;
+
+/// The max number of roles of the registry, i.e., one entry per role
+#[constant]
+pub const MAX_ROLES: u8 = 4;
+
+/// The current layout version of the config account
+#[constant]
+pub const CONFIG_VERSION: u8 = 1;
+
+/// The current layout version of the lbp accounts
+#[constant]
+pub const LBP_VERSION: u8 = 1;
+
+/// The current layout version of the position accounts
+#[constant]
+pub const POSITION_VERSION: u8 = 1;
@@ -0,0 +1,42 @@
+//! Concentrated liquidity math shared by the backends using sqrt prices in Q64.64
+
+/// The min sqrt price supported by the concentrated liquidity DEXes, i.e., sqrt(1.0001^-443636) in Q64.64
+pub const MIN_SQRT_PRICE_X64: u128 = 4_295_048_016;
+/// The max sqrt price supported by the concentrated liquidity DEXes, i.e., sqrt(1.0001^443636) in Q64.64
+pub const MAX_SQRT_PRICE_X64: u128 = 79_226_673_515_401_279_992_447_579_055;
+
+/// Returns the integer square root of `value`, rounded down
+pub fn isqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+
+    let mut x = value;
+    let mut y = (x + value / x) / 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+/// Returns the sqrt price in Q64.64 of a pool initialised with `amount_a` and `amount_b`,
+/// i.e., sqrt(amount_b / amount_a) * 2^64, clamped to the supported range
+pub fn sqrt_price_x64(amount_a: u64, amount_b: u64) -> u128 {
+    // sqrt((b << 64) / a) << 32 keeps the intermediate values within 128 bits
+    let ratio_x64 = ((amount_b as u128) << 64) / (amount_a as u128).max(1);
+    (isqrt(ratio_x64) << 32).clamp(MIN_SQRT_PRICE_X64, MAX_SQRT_PRICE_X64)
+}
+
+/// Returns the liquidity of a full range position funded with at most `amount_a` and `amount_b`
+/// at the sqrt price `sqrt_price_x64`
+///
+/// Over the full range, the liquidity is bounded by amount_a * sqrt_price and amount_b / sqrt_price.
+/// Those bounds slightly underestimate the exact liquidity, and one basis point is removed on top
+/// so that the amounts rounded up by the DEX never exceed the amounts provided.
+pub fn full_range_liquidity(amount_a: u64, amount_b: u64, sqrt_price_x64: u128) -> u128 {
+    let liquidity_a = ((amount_a as u128) * (sqrt_price_x64 >> 32)) >> 32;
+    let liquidity_b = ((amount_b as u128) << 64) / sqrt_price_x64.max(1);
+    let liquidity = liquidity_a.min(liquidity_b);
+    liquidity - liquidity / 10_000
+}
@@ -0,0 +1,330 @@
+//! Meteora DAMM (v2) backend
+//!
+//! The pool is created and funded by a single `initialize_pool` instruction which also opens the
+//! position holding the liquidity. The position nft mint must sign its creation, it is a PDA of
+//! this program derived from the lbp.
+//! The trading fees are harvested by claiming the fees of the position.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::dex::math::{full_range_liquidity, sqrt_price_x64};
+use crate::dex::{CreatePoolAccounts, HarvestFeesAccounts, LpToken};
+use crate::errors::ErrorCode;
+use crate::utils::sighash;
+
+/// The program id of Meteora DAMM on mainnet, pinned by default in the config
+pub const ID: Pubkey = pubkey!("cpamdpZCGKUy5JxQXB4dcpGPiikHawvSWAd6mEn1sGG");
+
+/// The Meteora DAMM accounts, in the order expected in the remaining accounts
+pub struct MeteoraDammAccounts<'a, 'info> {
+    pub dex_program: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub pool_authority: &'a AccountInfo<'info>,
+    pub pool: &'a AccountInfo<'info>,
+    pub position: &'a AccountInfo<'info>,
+    pub position_nft_mint: &'a AccountInfo<'info>,
+    pub position_nft_account: &'a AccountInfo<'info>,
+    pub token_a_vault: &'a AccountInfo<'info>,
+    pub token_b_vault: &'a AccountInfo<'info>,
+    pub token_2022_program: &'a AccountInfo<'info>,
+    pub event_authority: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> MeteoraDammAccounts<'a, 'info> {
+    pub const LEN: usize = 11;
+
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self> {
+        if accounts.len() != Self::LEN {
+            return err!(ErrorCode::InvalidDexAccounts);
+        }
+
+        Ok(Self {
+            dex_program: &accounts[0],
+            config: &accounts[1],
+            pool_authority: &accounts[2],
+            pool: &accounts[3],
+            position: &accounts[4],
+            position_nft_mint: &accounts[5],
+            position_nft_account: &accounts[6],
+            token_a_vault: &accounts[7],
+            token_b_vault: &accounts[8],
+            token_2022_program: &accounts[9],
+            event_authority: &accounts[10],
+        })
+    }
+}
+
+/// Returns the pda and bump of the mint of the position nft holding the liquidity
+pub fn position_nft_mint_address(lbp: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"damm_position_mint".as_ref(), lbp.as_ref()], &crate::ID)
+}
+
+pub fn create_pool<'info>(
+    accounts: &CreatePoolAccounts<'_, 'info>,
+    dex_accounts: &[AccountInfo<'info>],
+    launched_token_amount: u64,
+    raised_token_amount: u64,
+    open_time: u64,
+    signer_seeds: &[&[u8]],
+) -> Result<LpToken<'info>> {
+    let dex_accounts = MeteoraDammAccounts::load(dex_accounts)?;
+
+    let dex_program = accounts.dex_program;
+
+    if dex_accounts.dex_program.key() != dex_program {
+        return err!(ErrorCode::InvalidDexProgram);
+    }
+
+    if *dex_accounts.config.owner != dex_program || dex_accounts.token_2022_program.key() != anchor_spl::token_2022::ID {
+        return err!(ErrorCode::InvalidDexAccounts);
+    }
+
+    let (token_a, token_b) = accounts.sorted(launched_token_amount, raised_token_amount);
+
+    // Validate the derived accounts, the pool seeds take the greatest mint first
+    let (pool, _) = Pubkey::find_program_address(
+        &[
+            b"pool".as_ref(),
+            dex_accounts.config.key().as_ref(),
+            token_b.mint.key().as_ref(),
+            token_a.mint.key().as_ref(),
+        ],
+        &dex_program,
+    );
+    let (pool_authority, _) = Pubkey::find_program_address(&[b"pool_authority".as_ref()], &dex_program);
+    let (event_authority, _) = Pubkey::find_program_address(&[b"__event_authority".as_ref()], &dex_program);
+    let (position_nft_mint, position_nft_mint_bump) = position_nft_mint_address(&accounts.lbp);
+    let (position, _) =
+        Pubkey::find_program_address(&[b"position".as_ref(), position_nft_mint.as_ref()], &dex_program);
+    let (position_nft_account, _) = Pubkey::find_program_address(
+        &[b"position_nft_account".as_ref(), position_nft_mint.as_ref()],
+        &dex_program,
+    );
+    let (token_a_vault, _) = Pubkey::find_program_address(
+        &[b"token_vault".as_ref(), token_a.mint.key().as_ref(), pool.as_ref()],
+        &dex_program,
+    );
+    let (token_b_vault, _) = Pubkey::find_program_address(
+        &[b"token_vault".as_ref(), token_b.mint.key().as_ref(), pool.as_ref()],
+        &dex_program,
+    );
+
+    if dex_accounts.pool.key() != pool
+        || dex_accounts.pool_authority.key() != pool_authority
+        || dex_accounts.event_authority.key() != event_authority
+        || dex_accounts.position_nft_mint.key() != position_nft_mint
+        || dex_accounts.position.key() != position
+        || dex_accounts.position_nft_account.key() != position_nft_account
+        || dex_accounts.token_a_vault.key() != token_a_vault
+        || dex_accounts.token_b_vault.key() != token_b_vault
+    {
+        return err!(ErrorCode::InvalidDexAccounts);
+    }
+
+    let lbp_key = accounts.lbp;
+    let position_nft_mint_seeds: &[&[u8]] = &[
+        b"damm_position_mint",
+        lbp_key.as_ref(),
+        &[position_nft_mint_bump],
+    ];
+
+    // The liquidity of DAMM is expressed in Q64.64
+    let sqrt_price = sqrt_price_x64(token_a.amount, token_b.amount);
+    let liquidity = full_range_liquidity(token_a.amount, token_b.amount, sqrt_price)
+        .checked_mul(1 << 64)
+        .ok_or(ErrorCode::MathOverflow)?;
+    // The config must activate the pools by timestamp, and the activation point must be in the future
+    let activation_point = if open_time > Clock::get()?.unix_timestamp as u64 {
+        Some(open_time)
+    } else {
+        None
+    };
+
+    let mut data = Vec::with_capacity(42);
+    sighash("global", "initialize_pool").serialize(&mut data)?;
+    liquidity.serialize(&mut data)?;
+    sqrt_price.serialize(&mut data)?;
+    activation_point.serialize(&mut data)?;
+
+    invoke_signed(
+        &Instruction {
+            program_id: dex_program,
+            accounts: vec![
+                AccountMeta::new_readonly(accounts.creator.key(), false),
+                AccountMeta::new(position_nft_mint, true),
+                AccountMeta::new(position_nft_account, false),
+                AccountMeta::new(accounts.creator.key(), true),
+                AccountMeta::new_readonly(dex_accounts.config.key(), false),
+                AccountMeta::new_readonly(pool_authority, false),
+                AccountMeta::new(pool, false),
+                AccountMeta::new(position, false),
+                AccountMeta::new_readonly(token_a.mint.key(), false),
+                AccountMeta::new_readonly(token_b.mint.key(), false),
+                AccountMeta::new(token_a_vault, false),
+                AccountMeta::new(token_b_vault, false),
+                AccountMeta::new(token_a.creator_token.key(), false),
+                AccountMeta::new(token_b.creator_token.key(), false),
+                AccountMeta::new_readonly(accounts.token_program.key(), false),
+                AccountMeta::new_readonly(accounts.token_program.key(), false),
+                AccountMeta::new_readonly(dex_accounts.token_2022_program.key(), false),
+                AccountMeta::new_readonly(accounts.system_program.key(), false),
+                AccountMeta::new_readonly(event_authority, false),
+                AccountMeta::new_readonly(dex_program, false),
+            ],
+            data,
+        },
+        &[
+            accounts.creator.clone(),
+            dex_accounts.position_nft_mint.clone(),
+            dex_accounts.position_nft_account.clone(),
+            dex_accounts.config.clone(),
+            dex_accounts.pool_authority.clone(),
+            dex_accounts.pool.clone(),
+            dex_accounts.position.clone(),
+            token_a.mint.clone(),
+            token_b.mint.clone(),
+            dex_accounts.token_a_vault.clone(),
+            dex_accounts.token_b_vault.clone(),
+            token_a.creator_token.clone(),
+            token_b.creator_token.clone(),
+            accounts.token_program.clone(),
+            dex_accounts.token_2022_program.clone(),
+            accounts.system_program.clone(),
+            dex_accounts.event_authority.clone(),
+            dex_accounts.dex_program.clone(),
+        ],
+        &[signer_seeds, position_nft_mint_seeds],
+    )?;
+
+    Ok(LpToken {
+        mint: dex_accounts.position_nft_mint.clone(),
+        token_account: dex_accounts.position_nft_account.clone(),
+        token_program: dex_accounts.token_2022_program.clone(),
+        fungible: false,
+    })
+}
+
+/// The Meteora DAMM accounts to harvest the fees, in the order expected in the remaining accounts
+pub struct MeteoraDammHarvestAccounts<'a, 'info> {
+    pub dex_program: &'a AccountInfo<'info>,
+    pub pool_authority: &'a AccountInfo<'info>,
+    pub pool: &'a AccountInfo<'info>,
+    pub position: &'a AccountInfo<'info>,
+    pub position_nft_account: &'a AccountInfo<'info>,
+    pub token_a_vault: &'a AccountInfo<'info>,
+    pub token_b_vault: &'a AccountInfo<'info>,
+    pub event_authority: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> MeteoraDammHarvestAccounts<'a, 'info> {
+    pub const LEN: usize = 8;
+
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self> {
+        if accounts.len() != Self::LEN {
+            return err!(ErrorCode::InvalidDexAccounts);
+        }
+
+        Ok(Self {
+            dex_program: &accounts[0],
+            pool_authority: &accounts[1],
+            pool: &accounts[2],
+            position: &accounts[3],
+            position_nft_account: &accounts[4],
+            token_a_vault: &accounts[5],
+            token_b_vault: &accounts[6],
+            event_authority: &accounts[7],
+        })
+    }
+}
+
+pub fn harvest_fees<'info>(
+    accounts: &HarvestFeesAccounts<'_, 'info>,
+    dex_accounts: &[AccountInfo<'info>],
+    signer_seeds: &[&[u8]],
+) -> Result<u64> {
+    let dex_accounts = MeteoraDammHarvestAccounts::load(dex_accounts)?;
+
+    let dex_program = accounts.dex_program;
+
+    if dex_accounts.dex_program.key() != dex_program {
+        return err!(ErrorCode::InvalidDexProgram);
+    }
+
+    let (token_a, token_b) = accounts.sorted();
+
+    // Validate the derived accounts, the pool is checked against the position by Meteora
+    let pool = dex_accounts.pool.key();
+    let (pool_authority, _) = Pubkey::find_program_address(&[b"pool_authority".as_ref()], &dex_program);
+    let (event_authority, _) = Pubkey::find_program_address(&[b"__event_authority".as_ref()], &dex_program);
+    let (position, _) =
+        Pubkey::find_program_address(&[b"position".as_ref(), accounts.lp_mint.as_ref()], &dex_program);
+    let (position_nft_account, _) = Pubkey::find_program_address(
+        &[b"position_nft_account".as_ref(), accounts.lp_mint.as_ref()],
+        &dex_program,
+    );
+    let (token_a_vault, _) = Pubkey::find_program_address(
+        &[b"token_vault".as_ref(), token_a.mint.key().as_ref(), pool.as_ref()],
+        &dex_program,
+    );
+    let (token_b_vault, _) = Pubkey::find_program_address(
+        &[b"token_vault".as_ref(), token_b.mint.key().as_ref(), pool.as_ref()],
+        &dex_program,
+    );
+
+    if accounts.lp_mint != position_nft_mint_address(&accounts.lbp).0
+        || dex_accounts.pool_authority.key() != pool_authority
+        || dex_accounts.event_authority.key() != event_authority
+        || dex_accounts.position.key() != position
+        || dex_accounts.position_nft_account.key() != position_nft_account
+        || dex_accounts.token_a_vault.key() != token_a_vault
+        || dex_accounts.token_b_vault.key() != token_b_vault
+    {
+        return err!(ErrorCode::InvalidDexAccounts);
+    }
+
+    invoke_signed(
+        &Instruction {
+            program_id: dex_program,
+            accounts: vec![
+                AccountMeta::new_readonly(pool_authority, false),
+                AccountMeta::new_readonly(pool, false),
+                AccountMeta::new(position, false),
+                AccountMeta::new(token_a.owner_token.key(), false),
+                AccountMeta::new(token_b.owner_token.key(), false),
+                AccountMeta::new(token_a_vault, false),
+                AccountMeta::new(token_b_vault, false),
+                AccountMeta::new_readonly(token_a.mint.key(), false),
+                AccountMeta::new_readonly(token_b.mint.key(), false),
+                AccountMeta::new_readonly(position_nft_account, false),
+                AccountMeta::new_readonly(accounts.owner.key(), true),
+                AccountMeta::new_readonly(accounts.token_program.key(), false),
+                AccountMeta::new_readonly(accounts.token_program.key(), false),
+                AccountMeta::new_readonly(event_authority, false),
+                AccountMeta::new_readonly(dex_program, false),
+            ],
+            data: sighash("global", "claim_position_fee").to_vec(),
+        },
+        &[
+            dex_accounts.pool_authority.clone(),
+            dex_accounts.pool.clone(),
+            dex_accounts.position.clone(),
+            token_a.owner_token.clone(),
+            token_b.owner_token.clone(),
+            dex_accounts.token_a_vault.clone(),
+            dex_accounts.token_b_vault.clone(),
+            token_a.mint.clone(),
+            token_b.mint.clone(),
+            dex_accounts.position_nft_account.clone(),
+            accounts.owner.clone(),
+            accounts.token_program.clone(),
+            dex_accounts.event_authority.clone(),
+            dex_accounts.dex_program.clone(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    // The liquidity is left untouched
+    Ok(0)
+}
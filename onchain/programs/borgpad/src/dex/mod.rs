@@ -0,0 +1,190 @@
+//! Pool creation and fee harvesting on the DEXes supported by the program
+//!
+//! Each backend reads its own accounts from the remaining accounts of the instruction,
+//! validates them and encodes the instruction(s) creating the pool and adding the liquidity,
+//! or collecting the trading fees earned by the liquidity.
+
+use anchor_lang::prelude::*;
+
+use crate::state::lbp::Dex;
+
+pub mod math;
+pub mod meteora_damm;
+pub mod orca_whirlpool;
+pub mod raydium_cp_swap;
+
+/// The accounts shared by every backend to create a pool
+pub struct CreatePoolAccounts<'a, 'info> {
+    /// The lbp the pool is created for
+    pub lbp: Pubkey,
+    /// The program id pinned in the config for the DEX of the lbp
+    pub dex_program: Pubkey,
+    /// The signer creating the pool and providing the liquidity
+    pub creator: &'a AccountInfo<'info>,
+    pub launched_token_mint: &'a AccountInfo<'info>,
+    pub raised_token_mint: &'a AccountInfo<'info>,
+    /// The token account of the creator holding the launched tokens
+    pub creator_launched_token: &'a AccountInfo<'info>,
+    /// The token account of the creator holding the raised tokens
+    pub creator_raised_token: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub rent: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> CreatePoolAccounts<'a, 'info> {
+    /// Returns the launched and raised token accounts and amounts ordered by mint, as the DEXes expect
+    /// the mint of the token 0 (or A) to be smaller than the one of the token 1 (or B)
+    pub fn sorted(
+        &self,
+        launched_token_amount: u64,
+        raised_token_amount: u64,
+    ) -> (SortedToken<'a, 'info>, SortedToken<'a, 'info>) {
+        let launched = SortedToken {
+            mint: self.launched_token_mint,
+            creator_token: self.creator_launched_token,
+            amount: launched_token_amount,
+        };
+        let raised = SortedToken {
+            mint: self.raised_token_mint,
+            creator_token: self.creator_raised_token,
+            amount: raised_token_amount,
+        };
+
+        sort_by_mint(&self.launched_token_mint.key(), &self.raised_token_mint.key(), launched, raised)
+    }
+}
+
+/// The accounts shared by every backend to harvest the trading fees
+pub struct HarvestFeesAccounts<'a, 'info> {
+    /// The lbp the pool was created for
+    pub lbp: Pubkey,
+    /// The program id pinned in the config for the DEX of the lbp
+    pub dex_program: Pubkey,
+    /// The mint of the lp tokens, or of the position nft, of the lbp
+    pub lp_mint: Pubkey,
+    /// The signer owning the liquidity and receiving the fees
+    pub owner: &'a AccountInfo<'info>,
+    pub launched_token_mint: &'a AccountInfo<'info>,
+    pub raised_token_mint: &'a AccountInfo<'info>,
+    /// The token account of the owner receiving the launched token fees
+    pub owner_launched_token: &'a AccountInfo<'info>,
+    /// The token account of the owner receiving the raised token fees
+    pub owner_raised_token: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> HarvestFeesAccounts<'a, 'info> {
+    /// Returns the launched and raised token accounts ordered by mint
+    pub fn sorted(&self) -> (PoolToken<'a, 'info>, PoolToken<'a, 'info>) {
+        let launched = PoolToken {
+            mint: self.launched_token_mint,
+            owner_token: self.owner_launched_token,
+        };
+        let raised = PoolToken {
+            mint: self.raised_token_mint,
+            owner_token: self.owner_raised_token,
+        };
+
+        sort_by_mint(&self.launched_token_mint.key(), &self.raised_token_mint.key(), launched, raised)
+    }
+}
+
+/// Orders the launched and raised values by mint, the mint of the token 0 (or A) being the smallest
+pub fn sort_by_mint<T>(launched_token_mint: &Pubkey, raised_token_mint: &Pubkey, launched: T, raised: T) -> (T, T) {
+    if raised_token_mint < launched_token_mint {
+        (raised, launched)
+    } else {
+        (launched, raised)
+    }
+}
+
+/// A token of the pool along with the account of the owner receiving its fees
+pub struct PoolToken<'a, 'info> {
+    pub mint: &'a AccountInfo<'info>,
+    pub owner_token: &'a AccountInfo<'info>,
+}
+
+/// A token of the pool along with the creator account and the amount providing its liquidity
+pub struct SortedToken<'a, 'info> {
+    pub mint: &'a AccountInfo<'info>,
+    pub creator_token: &'a AccountInfo<'info>,
+    pub amount: u64,
+}
+
+/// The lp tokens, or the position nft, received by the creator of the pool
+pub struct LpToken<'info> {
+    pub mint: AccountInfo<'info>,
+    /// The token account of the creator holding the lp tokens
+    pub token_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    /// Whether the liquidity is represented by fungible lp tokens rather than a position nft
+    pub fungible: bool,
+}
+
+/// Creates the pool on the DEX selected by the lbp, the accounts specific to the DEX being
+/// passed as remaining accounts
+/// The trading is not open on the pool before `open_time`
+pub fn create_pool<'info>(
+    dex: &Dex,
+    accounts: &CreatePoolAccounts<'_, 'info>,
+    dex_accounts: &[AccountInfo<'info>],
+    launched_token_amount: u64,
+    raised_token_amount: u64,
+    open_time: u64,
+    signer_seeds: &[&[u8]],
+) -> Result<LpToken<'info>> {
+    match dex {
+        Dex::RaydiumCpSwap => raydium_cp_swap::create_pool(
+            accounts,
+            dex_accounts,
+            launched_token_amount,
+            raised_token_amount,
+            open_time,
+            signer_seeds,
+        ),
+        Dex::OrcaWhirlpool => orca_whirlpool::create_pool(
+            accounts,
+            dex_accounts,
+            launched_token_amount,
+            raised_token_amount,
+            open_time,
+            signer_seeds,
+        ),
+        Dex::MeteoraDamm => meteora_damm::create_pool(
+            accounts,
+            dex_accounts,
+            launched_token_amount,
+            raised_token_amount,
+            open_time,
+            signer_seeds,
+        ),
+    }
+}
+
+/// Collects the trading fees earned by the liquidity of the lbp into the token accounts of the owner,
+/// the accounts specific to the DEX being passed as remaining accounts
+/// `lp_token_amount` is the amount of lp tokens held by the owner, and `lp_value_x64` the value of
+/// one lp token at the last harvest, updated by the DEXes accruing the fees in the reserves
+/// Returns the amount of lp tokens withdrawn to collect the fees
+pub fn harvest_fees<'info>(
+    dex: &Dex,
+    accounts: &HarvestFeesAccounts<'_, 'info>,
+    dex_accounts: &[AccountInfo<'info>],
+    lp_token_amount: u64,
+    lp_value_x64: &mut u128,
+    signer_seeds: &[&[u8]],
+) -> Result<u64> {
+    match dex {
+        Dex::RaydiumCpSwap => raydium_cp_swap::harvest_fees(
+            accounts,
+            dex_accounts,
+            lp_token_amount,
+            lp_value_x64,
+            signer_seeds,
+        ),
+        Dex::OrcaWhirlpool => orca_whirlpool::harvest_fees(accounts, dex_accounts, signer_seeds),
+        Dex::MeteoraDamm => meteora_damm::harvest_fees(accounts, dex_accounts, signer_seeds),
+    }
+}
@@ -0,0 +1,488 @@
+//! Orca Whirlpool backend
+//!
+//! The pool is created by `initialize_pool`, then the liquidity is added on the full price range
+//! by initialising the two bounding tick arrays, opening a position and increasing its liquidity.
+//! The token vaults of the pool and the position mint must sign their creation, they are PDAs
+//! of this program derived from the lbp.
+//! The trading fees are harvested by updating the fees owed to the position, then collecting them.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::associated_token::get_associated_token_address;
+
+use crate::dex::math::{full_range_liquidity, sqrt_price_x64};
+use crate::dex::{CreatePoolAccounts, HarvestFeesAccounts, LpToken};
+use crate::errors::ErrorCode;
+use crate::utils::sighash;
+
+/// The program id of Orca Whirlpool on mainnet, pinned by default in the config
+pub const ID: Pubkey = pubkey!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
+
+const MIN_TICK_INDEX: i32 = -443_636;
+const MAX_TICK_INDEX: i32 = 443_636;
+const TICK_ARRAY_SIZE: i32 = 88;
+
+/// The offset of the tick spacing in the fee tier account, after the discriminator and the whirlpools config
+const FEE_TIER_TICK_SPACING_OFFSET: usize = 8 + 32;
+
+/// The Orca Whirlpool accounts, in the order expected in the remaining accounts
+pub struct OrcaWhirlpoolAccounts<'a, 'info> {
+    pub dex_program: &'a AccountInfo<'info>,
+    pub whirlpools_config: &'a AccountInfo<'info>,
+    pub fee_tier: &'a AccountInfo<'info>,
+    pub whirlpool: &'a AccountInfo<'info>,
+    pub token_vault_a: &'a AccountInfo<'info>,
+    pub token_vault_b: &'a AccountInfo<'info>,
+    pub tick_array_lower: &'a AccountInfo<'info>,
+    pub tick_array_upper: &'a AccountInfo<'info>,
+    pub position: &'a AccountInfo<'info>,
+    pub position_mint: &'a AccountInfo<'info>,
+    pub position_token_account: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> OrcaWhirlpoolAccounts<'a, 'info> {
+    pub const LEN: usize = 11;
+
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self> {
+        if accounts.len() != Self::LEN {
+            return err!(ErrorCode::InvalidDexAccounts);
+        }
+
+        Ok(Self {
+            dex_program: &accounts[0],
+            whirlpools_config: &accounts[1],
+            fee_tier: &accounts[2],
+            whirlpool: &accounts[3],
+            token_vault_a: &accounts[4],
+            token_vault_b: &accounts[5],
+            tick_array_lower: &accounts[6],
+            tick_array_upper: &accounts[7],
+            position: &accounts[8],
+            position_mint: &accounts[9],
+            position_token_account: &accounts[10],
+        })
+    }
+
+    /// Returns the tick spacing of the fee tier after checking it belongs to the whirlpools config
+    fn tick_spacing(&self, dex_program: &Pubkey) -> Result<u16> {
+        if self.whirlpools_config.owner != dex_program || self.fee_tier.owner != dex_program {
+            return err!(ErrorCode::InvalidDexAccounts);
+        }
+
+        let data = self.fee_tier.try_borrow_data()?;
+        if data.len() < FEE_TIER_TICK_SPACING_OFFSET + 2
+            || data[8..FEE_TIER_TICK_SPACING_OFFSET] != self.whirlpools_config.key().to_bytes()
+        {
+            return err!(ErrorCode::InvalidDexAccounts);
+        }
+
+        Ok(u16::from_le_bytes([
+            data[FEE_TIER_TICK_SPACING_OFFSET],
+            data[FEE_TIER_TICK_SPACING_OFFSET + 1],
+        ]))
+    }
+}
+
+/// Returns the pda and bump of the token vault created for `mint`
+pub fn token_vault_address(lbp: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"whirlpool_vault".as_ref(), lbp.as_ref(), mint.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Returns the pda and bump of the mint of the position holding the liquidity
+pub fn position_mint_address(lbp: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"whirlpool_position_mint".as_ref(), lbp.as_ref()], &crate::ID)
+}
+
+/// Returns the start index of the tick array containing `tick_index`
+fn tick_array_start_index(tick_index: i32, tick_spacing: u16) -> i32 {
+    let ticks_in_array = TICK_ARRAY_SIZE * tick_spacing as i32;
+    tick_index.div_euclid(ticks_in_array) * ticks_in_array
+}
+
+fn tick_array_address(dex_program: &Pubkey, whirlpool: &Pubkey, start_tick_index: i32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"tick_array".as_ref(),
+            whirlpool.as_ref(),
+            start_tick_index.to_string().as_bytes(),
+        ],
+        dex_program,
+    )
+    .0
+}
+
+pub fn create_pool<'info>(
+    accounts: &CreatePoolAccounts<'_, 'info>,
+    dex_accounts: &[AccountInfo<'info>],
+    launched_token_amount: u64,
+    raised_token_amount: u64,
+    open_time: u64,
+    signer_seeds: &[&[u8]],
+) -> Result<LpToken<'info>> {
+    let dex_accounts = OrcaWhirlpoolAccounts::load(dex_accounts)?;
+
+    // Whirlpools have no trading open time, so the pool is not created before it
+    if (Clock::get()?.unix_timestamp as u64) < open_time {
+        return err!(ErrorCode::PoolNotOpen);
+    }
+
+    let dex_program = accounts.dex_program;
+
+    if dex_accounts.dex_program.key() != dex_program {
+        return err!(ErrorCode::InvalidDexProgram);
+    }
+
+    let tick_spacing = dex_accounts.tick_spacing(&dex_program)?;
+    let (token_a, token_b) = accounts.sorted(launched_token_amount, raised_token_amount);
+
+    // Validate the derived accounts
+    let (whirlpool, whirlpool_bump) = Pubkey::find_program_address(
+        &[
+            b"whirlpool".as_ref(),
+            dex_accounts.whirlpools_config.key().as_ref(),
+            token_a.mint.key().as_ref(),
+            token_b.mint.key().as_ref(),
+            &tick_spacing.to_le_bytes(),
+        ],
+        &dex_program,
+    );
+    let (fee_tier, _) = Pubkey::find_program_address(
+        &[
+            b"fee_tier".as_ref(),
+            dex_accounts.whirlpools_config.key().as_ref(),
+            &tick_spacing.to_le_bytes(),
+        ],
+        &dex_program,
+    );
+    let (token_vault_a, token_vault_a_bump) = token_vault_address(&accounts.lbp, &token_a.mint.key());
+    let (token_vault_b, token_vault_b_bump) = token_vault_address(&accounts.lbp, &token_b.mint.key());
+    let (position_mint, position_mint_bump) = position_mint_address(&accounts.lbp);
+    let (position, position_bump) =
+        Pubkey::find_program_address(&[b"position".as_ref(), position_mint.as_ref()], &dex_program);
+
+    // Full range position
+    let tick_lower_index = MIN_TICK_INDEX / tick_spacing as i32 * tick_spacing as i32;
+    let tick_upper_index = MAX_TICK_INDEX / tick_spacing as i32 * tick_spacing as i32;
+    let tick_array_lower_start = tick_array_start_index(tick_lower_index, tick_spacing);
+    let tick_array_upper_start = tick_array_start_index(tick_upper_index, tick_spacing);
+
+    if dex_accounts.whirlpool.key() != whirlpool
+        || dex_accounts.fee_tier.key() != fee_tier
+        || dex_accounts.token_vault_a.key() != token_vault_a
+        || dex_accounts.token_vault_b.key() != token_vault_b
+        || dex_accounts.position_mint.key() != position_mint
+        || dex_accounts.position.key() != position
+        || dex_accounts.position_token_account.key()
+            != get_associated_token_address(&accounts.creator.key(), &position_mint)
+        || dex_accounts.tick_array_lower.key() != tick_array_address(&dex_program, &whirlpool, tick_array_lower_start)
+        || dex_accounts.tick_array_upper.key() != tick_array_address(&dex_program, &whirlpool, tick_array_upper_start)
+    {
+        return err!(ErrorCode::InvalidDexAccounts);
+    }
+
+    let lbp_key = accounts.lbp;
+    let token_a_mint_key = token_a.mint.key();
+    let token_b_mint_key = token_b.mint.key();
+    let token_vault_a_seeds: &[&[u8]] = &[
+        b"whirlpool_vault",
+        lbp_key.as_ref(),
+        token_a_mint_key.as_ref(),
+        &[token_vault_a_bump],
+    ];
+    let token_vault_b_seeds: &[&[u8]] = &[
+        b"whirlpool_vault",
+        lbp_key.as_ref(),
+        token_b_mint_key.as_ref(),
+        &[token_vault_b_bump],
+    ];
+    let position_mint_seeds: &[&[u8]] = &[
+        b"whirlpool_position_mint",
+        lbp_key.as_ref(),
+        &[position_mint_bump],
+    ];
+
+    // Initialize pool
+    let sqrt_price = sqrt_price_x64(token_a.amount, token_b.amount);
+
+    let mut data = Vec::with_capacity(27);
+    sighash("global", "initialize_pool").serialize(&mut data)?;
+    whirlpool_bump.serialize(&mut data)?;
+    tick_spacing.serialize(&mut data)?;
+    sqrt_price.serialize(&mut data)?;
+
+    invoke_signed(
+        &Instruction {
+            program_id: dex_program,
+            accounts: vec![
+                AccountMeta::new_readonly(dex_accounts.whirlpools_config.key(), false),
+                AccountMeta::new_readonly(token_a.mint.key(), false),
+                AccountMeta::new_readonly(token_b.mint.key(), false),
+                AccountMeta::new(accounts.creator.key(), true),
+                AccountMeta::new(whirlpool, false),
+                AccountMeta::new(token_vault_a, true),
+                AccountMeta::new(token_vault_b, true),
+                AccountMeta::new_readonly(fee_tier, false),
+                AccountMeta::new_readonly(accounts.token_program.key(), false),
+                AccountMeta::new_readonly(accounts.system_program.key(), false),
+                AccountMeta::new_readonly(accounts.rent.key(), false),
+            ],
+            data,
+        },
+        &[
+            dex_accounts.whirlpools_config.clone(),
+            token_a.mint.clone(),
+            token_b.mint.clone(),
+            accounts.creator.clone(),
+            dex_accounts.whirlpool.clone(),
+            dex_accounts.token_vault_a.clone(),
+            dex_accounts.token_vault_b.clone(),
+            dex_accounts.fee_tier.clone(),
+            accounts.token_program.clone(),
+            accounts.system_program.clone(),
+            accounts.rent.clone(),
+        ],
+        &[signer_seeds, token_vault_a_seeds, token_vault_b_seeds],
+    )?;
+
+    // Initialize the tick arrays bounding the full range
+    for (tick_array, start_tick_index) in [
+        (dex_accounts.tick_array_lower, tick_array_lower_start),
+        (dex_accounts.tick_array_upper, tick_array_upper_start),
+    ] {
+        let mut data = Vec::with_capacity(12);
+        sighash("global", "initialize_tick_array").serialize(&mut data)?;
+        start_tick_index.serialize(&mut data)?;
+
+        invoke_signed(
+            &Instruction {
+                program_id: dex_program,
+                accounts: vec![
+                    AccountMeta::new_readonly(whirlpool, false),
+                    AccountMeta::new(accounts.creator.key(), true),
+                    AccountMeta::new(tick_array.key(), false),
+                    AccountMeta::new_readonly(accounts.system_program.key(), false),
+                ],
+                data,
+            },
+            &[
+                dex_accounts.whirlpool.clone(),
+                accounts.creator.clone(),
+                tick_array.clone(),
+                accounts.system_program.clone(),
+            ],
+            &[signer_seeds],
+        )?;
+    }
+
+    // Open the full range position
+    let mut data = Vec::with_capacity(17);
+    sighash("global", "open_position").serialize(&mut data)?;
+    position_bump.serialize(&mut data)?;
+    tick_lower_index.serialize(&mut data)?;
+    tick_upper_index.serialize(&mut data)?;
+
+    invoke_signed(
+        &Instruction {
+            program_id: dex_program,
+            accounts: vec![
+                AccountMeta::new(accounts.creator.key(), true),
+                AccountMeta::new_readonly(accounts.creator.key(), false),
+                AccountMeta::new(position, false),
+                AccountMeta::new(position_mint, true),
+                AccountMeta::new(dex_accounts.position_token_account.key(), false),
+                AccountMeta::new_readonly(whirlpool, false),
+                AccountMeta::new_readonly(accounts.token_program.key(), false),
+                AccountMeta::new_readonly(accounts.system_program.key(), false),
+                AccountMeta::new_readonly(accounts.rent.key(), false),
+                AccountMeta::new_readonly(accounts.associated_token_program.key(), false),
+            ],
+            data,
+        },
+        &[
+            accounts.creator.clone(),
+            dex_accounts.position.clone(),
+            dex_accounts.position_mint.clone(),
+            dex_accounts.position_token_account.clone(),
+            dex_accounts.whirlpool.clone(),
+            accounts.token_program.clone(),
+            accounts.system_program.clone(),
+            accounts.rent.clone(),
+            accounts.associated_token_program.clone(),
+        ],
+        &[signer_seeds, position_mint_seeds],
+    )?;
+
+    // Add the liquidity
+    let liquidity = full_range_liquidity(token_a.amount, token_b.amount, sqrt_price);
+
+    let mut data = Vec::with_capacity(40);
+    sighash("global", "increase_liquidity").serialize(&mut data)?;
+    liquidity.serialize(&mut data)?;
+    token_a.amount.serialize(&mut data)?;
+    token_b.amount.serialize(&mut data)?;
+
+    invoke_signed(
+        &Instruction {
+            program_id: dex_program,
+            accounts: vec![
+                AccountMeta::new(whirlpool, false),
+                AccountMeta::new_readonly(accounts.token_program.key(), false),
+                AccountMeta::new_readonly(accounts.creator.key(), true),
+                AccountMeta::new(position, false),
+                AccountMeta::new_readonly(dex_accounts.position_token_account.key(), false),
+                AccountMeta::new(token_a.creator_token.key(), false),
+                AccountMeta::new(token_b.creator_token.key(), false),
+                AccountMeta::new(token_vault_a, false),
+                AccountMeta::new(token_vault_b, false),
+                AccountMeta::new(dex_accounts.tick_array_lower.key(), false),
+                AccountMeta::new(dex_accounts.tick_array_upper.key(), false),
+            ],
+            data,
+        },
+        &[
+            dex_accounts.whirlpool.clone(),
+            accounts.token_program.clone(),
+            accounts.creator.clone(),
+            dex_accounts.position.clone(),
+            dex_accounts.position_token_account.clone(),
+            token_a.creator_token.clone(),
+            token_b.creator_token.clone(),
+            dex_accounts.token_vault_a.clone(),
+            dex_accounts.token_vault_b.clone(),
+            dex_accounts.tick_array_lower.clone(),
+            dex_accounts.tick_array_upper.clone(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    Ok(LpToken {
+        mint: dex_accounts.position_mint.clone(),
+        token_account: dex_accounts.position_token_account.clone(),
+        token_program: accounts.token_program.clone(),
+        fungible: false,
+    })
+}
+
+/// The Orca Whirlpool accounts to harvest the fees, in the order expected in the remaining accounts
+pub struct OrcaWhirlpoolHarvestAccounts<'a, 'info> {
+    pub dex_program: &'a AccountInfo<'info>,
+    pub whirlpool: &'a AccountInfo<'info>,
+    pub position: &'a AccountInfo<'info>,
+    pub position_token_account: &'a AccountInfo<'info>,
+    pub tick_array_lower: &'a AccountInfo<'info>,
+    pub tick_array_upper: &'a AccountInfo<'info>,
+    pub token_vault_a: &'a AccountInfo<'info>,
+    pub token_vault_b: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> OrcaWhirlpoolHarvestAccounts<'a, 'info> {
+    pub const LEN: usize = 8;
+
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self> {
+        if accounts.len() != Self::LEN {
+            return err!(ErrorCode::InvalidDexAccounts);
+        }
+
+        Ok(Self {
+            dex_program: &accounts[0],
+            whirlpool: &accounts[1],
+            position: &accounts[2],
+            position_token_account: &accounts[3],
+            tick_array_lower: &accounts[4],
+            tick_array_upper: &accounts[5],
+            token_vault_a: &accounts[6],
+            token_vault_b: &accounts[7],
+        })
+    }
+}
+
+pub fn harvest_fees<'info>(
+    accounts: &HarvestFeesAccounts<'_, 'info>,
+    dex_accounts: &[AccountInfo<'info>],
+    signer_seeds: &[&[u8]],
+) -> Result<u64> {
+    let dex_accounts = OrcaWhirlpoolHarvestAccounts::load(dex_accounts)?;
+
+    let dex_program = accounts.dex_program;
+
+    if dex_accounts.dex_program.key() != dex_program {
+        return err!(ErrorCode::InvalidDexProgram);
+    }
+
+    let (token_a, token_b) = accounts.sorted();
+
+    // Validate the derived accounts, the whirlpool and the tick arrays are checked against the position by Orca
+    let (token_vault_a, _) = token_vault_address(&accounts.lbp, &token_a.mint.key());
+    let (token_vault_b, _) = token_vault_address(&accounts.lbp, &token_b.mint.key());
+    let (position, _) =
+        Pubkey::find_program_address(&[b"position".as_ref(), accounts.lp_mint.as_ref()], &dex_program);
+
+    if accounts.lp_mint != position_mint_address(&accounts.lbp).0
+        || dex_accounts.position.key() != position
+        || dex_accounts.position_token_account.key()
+            != get_associated_token_address(&accounts.owner.key(), &accounts.lp_mint)
+        || dex_accounts.token_vault_a.key() != token_vault_a
+        || dex_accounts.token_vault_b.key() != token_vault_b
+    {
+        return err!(ErrorCode::InvalidDexAccounts);
+    }
+
+    // Update the fees owed to the position
+    invoke_signed(
+        &Instruction {
+            program_id: dex_program,
+            accounts: vec![
+                AccountMeta::new(dex_accounts.whirlpool.key(), false),
+                AccountMeta::new(dex_accounts.position.key(), false),
+                AccountMeta::new_readonly(dex_accounts.tick_array_lower.key(), false),
+                AccountMeta::new_readonly(dex_accounts.tick_array_upper.key(), false),
+            ],
+            data: sighash("global", "update_fees_and_rewards").to_vec(),
+        },
+        &[
+            dex_accounts.whirlpool.clone(),
+            dex_accounts.position.clone(),
+            dex_accounts.tick_array_lower.clone(),
+            dex_accounts.tick_array_upper.clone(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    // Collect the fees owed to the position
+    invoke_signed(
+        &Instruction {
+            program_id: dex_program,
+            accounts: vec![
+                AccountMeta::new_readonly(dex_accounts.whirlpool.key(), false),
+                AccountMeta::new_readonly(accounts.owner.key(), true),
+                AccountMeta::new(dex_accounts.position.key(), false),
+                AccountMeta::new_readonly(dex_accounts.position_token_account.key(), false),
+                AccountMeta::new(token_a.owner_token.key(), false),
+                AccountMeta::new(token_vault_a, false),
+                AccountMeta::new(token_b.owner_token.key(), false),
+                AccountMeta::new(token_vault_b, false),
+                AccountMeta::new_readonly(accounts.token_program.key(), false),
+            ],
+            data: sighash("global", "collect_fees").to_vec(),
+        },
+        &[
+            dex_accounts.whirlpool.clone(),
+            accounts.owner.clone(),
+            dex_accounts.position.clone(),
+            dex_accounts.position_token_account.clone(),
+            token_a.owner_token.clone(),
+            dex_accounts.token_vault_a.clone(),
+            token_b.owner_token.clone(),
+            dex_accounts.token_vault_b.clone(),
+            accounts.token_program.clone(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    // The liquidity is left untouched
+    Ok(0)
+}
@@ -0,0 +1,403 @@
+//! Raydium CP-Swap backend
+//!
+//! The pool is created and funded by a single `initialize` instruction.
+//! The trading fees accrue in the reserves of the pool, they are harvested by withdrawing the lp tokens
+//! matching the growth of the value of the lp tokens since the last harvest.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::associated_token::get_associated_token_address;
+
+use crate::dex::math::isqrt;
+use crate::dex::{CreatePoolAccounts, HarvestFeesAccounts, LpToken};
+use crate::errors::ErrorCode;
+use crate::utils::sighash;
+
+/// The program id of Raydium CP-Swap on mainnet, pinned by default in the config
+pub const ID: Pubkey = pubkey!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
+
+/// The program id of SPL Memo, required by the withdrawal
+const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+/// The offsets of the fields read in the packed pool state, after the discriminator
+const POOL_STATE_TOKEN_0_VAULT_OFFSET: usize = 8 + 32 * 2;
+const POOL_STATE_TOKEN_1_VAULT_OFFSET: usize = 8 + 32 * 3;
+const POOL_STATE_LP_MINT_OFFSET: usize = 8 + 32 * 4;
+const POOL_STATE_TOKEN_0_MINT_OFFSET: usize = 8 + 32 * 5;
+const POOL_STATE_LP_SUPPLY_OFFSET: usize = 8 + 32 * 10 + 5;
+
+/// The offset of the amount in a token account
+const TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = 32 * 2;
+
+/// The Raydium CP-Swap accounts, in the order expected in the remaining accounts
+pub struct RaydiumCpSwapAccounts<'a, 'info> {
+    pub dex_program: &'a AccountInfo<'info>,
+    pub amm_config: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub pool_state: &'a AccountInfo<'info>,
+    pub lp_mint: &'a AccountInfo<'info>,
+    pub creator_lp_token: &'a AccountInfo<'info>,
+    pub launched_token_vault: &'a AccountInfo<'info>,
+    pub raised_token_vault: &'a AccountInfo<'info>,
+    pub create_pool_fee: &'a AccountInfo<'info>,
+    pub observation_state: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> RaydiumCpSwapAccounts<'a, 'info> {
+    pub const LEN: usize = 10;
+
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self> {
+        if accounts.len() != Self::LEN {
+            return err!(ErrorCode::InvalidDexAccounts);
+        }
+
+        Ok(Self {
+            dex_program: &accounts[0],
+            amm_config: &accounts[1],
+            authority: &accounts[2],
+            pool_state: &accounts[3],
+            lp_mint: &accounts[4],
+            creator_lp_token: &accounts[5],
+            launched_token_vault: &accounts[6],
+            raised_token_vault: &accounts[7],
+            create_pool_fee: &accounts[8],
+            observation_state: &accounts[9],
+        })
+    }
+
+    /// Checks the accounts are the ones derived by Raydium for the pool of `token_0_mint` and `token_1_mint`
+    /// The create pool fee receiver is checked by Raydium itself
+    fn validate(
+        &self,
+        dex_program: &Pubkey,
+        creator: &Pubkey,
+        token_0_mint: &Pubkey,
+        token_1_mint: &Pubkey,
+        launched_token_mint: &Pubkey,
+    ) -> Result<()> {
+        if self.dex_program.key() != *dex_program {
+            return err!(ErrorCode::InvalidDexProgram);
+        }
+
+        if self.amm_config.owner != dex_program {
+            return err!(ErrorCode::InvalidDexAccounts);
+        }
+
+        let (authority, _) =
+            Pubkey::find_program_address(&[b"vault_and_lp_mint_auth_seed".as_ref()], dex_program);
+        let (pool_state, _) = Pubkey::find_program_address(
+            &[
+                b"pool".as_ref(),
+                self.amm_config.key().as_ref(),
+                token_0_mint.as_ref(),
+                token_1_mint.as_ref(),
+            ],
+            dex_program,
+        );
+        let (lp_mint, _) = Pubkey::find_program_address(
+            &[b"pool_lp_mint".as_ref(), pool_state.as_ref()],
+            dex_program,
+        );
+        let (token_0_vault, _) = Pubkey::find_program_address(
+            &[b"pool_vault".as_ref(), pool_state.as_ref(), token_0_mint.as_ref()],
+            dex_program,
+        );
+        let (token_1_vault, _) = Pubkey::find_program_address(
+            &[b"pool_vault".as_ref(), pool_state.as_ref(), token_1_mint.as_ref()],
+            dex_program,
+        );
+        let (observation_state, _) = Pubkey::find_program_address(
+            &[b"observation".as_ref(), pool_state.as_ref()],
+            dex_program,
+        );
+
+        let (launched_token_vault, raised_token_vault) = if token_0_mint == launched_token_mint {
+            (token_0_vault, token_1_vault)
+        } else {
+            (token_1_vault, token_0_vault)
+        };
+
+        if self.authority.key() != authority
+            || self.pool_state.key() != pool_state
+            || self.lp_mint.key() != lp_mint
+            || self.creator_lp_token.key() != get_associated_token_address(creator, &lp_mint)
+            || self.launched_token_vault.key() != launched_token_vault
+            || self.raised_token_vault.key() != raised_token_vault
+            || self.observation_state.key() != observation_state
+        {
+            return err!(ErrorCode::InvalidDexAccounts);
+        }
+
+        Ok(())
+    }
+}
+
+pub fn create_pool<'info>(
+    accounts: &CreatePoolAccounts<'_, 'info>,
+    dex_accounts: &[AccountInfo<'info>],
+    launched_token_amount: u64,
+    raised_token_amount: u64,
+    open_time: u64,
+    signer_seeds: &[&[u8]],
+) -> Result<LpToken<'info>> {
+    let dex_accounts = RaydiumCpSwapAccounts::load(dex_accounts)?;
+
+    let (token_0, token_1) = accounts.sorted(launched_token_amount, raised_token_amount);
+
+    dex_accounts.validate(
+        &accounts.dex_program,
+        &accounts.creator.key(),
+        &token_0.mint.key(),
+        &token_1.mint.key(),
+        &accounts.launched_token_mint.key(),
+    )?;
+    let (token_0_vault, token_1_vault) = if token_0.mint.key() == accounts.launched_token_mint.key() {
+        (dex_accounts.launched_token_vault, dex_accounts.raised_token_vault)
+    } else {
+        (dex_accounts.raised_token_vault, dex_accounts.launched_token_vault)
+    };
+
+    let mut data = Vec::with_capacity(32);
+    sighash("global", "initialize").serialize(&mut data)?;
+    token_0.amount.serialize(&mut data)?;
+    token_1.amount.serialize(&mut data)?;
+    open_time.serialize(&mut data)?; // The swaps are rejected by the pool before this time
+
+    let account_metas = vec![
+        AccountMeta::new(accounts.creator.key(), true),
+        AccountMeta::new_readonly(dex_accounts.amm_config.key(), false),
+        AccountMeta::new_readonly(dex_accounts.authority.key(), false),
+        AccountMeta::new(dex_accounts.pool_state.key(), false),
+        AccountMeta::new_readonly(token_0.mint.key(), false),
+        AccountMeta::new_readonly(token_1.mint.key(), false),
+        AccountMeta::new(dex_accounts.lp_mint.key(), false),
+        AccountMeta::new(token_0.creator_token.key(), false),
+        AccountMeta::new(token_1.creator_token.key(), false),
+        AccountMeta::new(dex_accounts.creator_lp_token.key(), false),
+        AccountMeta::new(token_0_vault.key(), false),
+        AccountMeta::new(token_1_vault.key(), false),
+        AccountMeta::new(dex_accounts.create_pool_fee.key(), false),
+        AccountMeta::new(dex_accounts.observation_state.key(), false),
+        AccountMeta::new_readonly(accounts.token_program.key(), false),
+        AccountMeta::new_readonly(accounts.token_program.key(), false),
+        AccountMeta::new_readonly(accounts.token_program.key(), false),
+        AccountMeta::new_readonly(accounts.associated_token_program.key(), false),
+        AccountMeta::new_readonly(accounts.system_program.key(), false),
+        AccountMeta::new_readonly(accounts.rent.key(), false),
+    ];
+
+    let account_infos = [
+        accounts.creator.clone(),
+        dex_accounts.amm_config.clone(),
+        dex_accounts.authority.clone(),
+        dex_accounts.pool_state.clone(),
+        token_0.mint.clone(),
+        token_1.mint.clone(),
+        dex_accounts.lp_mint.clone(),
+        token_0.creator_token.clone(),
+        token_1.creator_token.clone(),
+        dex_accounts.creator_lp_token.clone(),
+        token_0_vault.clone(),
+        token_1_vault.clone(),
+        dex_accounts.create_pool_fee.clone(),
+        dex_accounts.observation_state.clone(),
+        accounts.token_program.clone(),
+        accounts.token_program.clone(),
+        accounts.token_program.clone(),
+        accounts.associated_token_program.clone(),
+        accounts.system_program.clone(),
+        accounts.rent.clone(),
+    ];
+
+    let instruction = Instruction {
+        program_id: accounts.dex_program,
+        accounts: account_metas,
+        data,
+    };
+
+    invoke_signed(&instruction, &account_infos, &[signer_seeds])?;
+
+    Ok(LpToken {
+        mint: dex_accounts.lp_mint.clone(),
+        token_account: dex_accounts.creator_lp_token.clone(),
+        token_program: accounts.token_program.clone(),
+        fungible: true,
+    })
+}
+
+/// The Raydium CP-Swap accounts to harvest the fees, in the order expected in the remaining accounts
+pub struct RaydiumCpSwapHarvestAccounts<'a, 'info> {
+    pub dex_program: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub pool_state: &'a AccountInfo<'info>,
+    pub owner_lp_token: &'a AccountInfo<'info>,
+    pub token_0_vault: &'a AccountInfo<'info>,
+    pub token_1_vault: &'a AccountInfo<'info>,
+    pub lp_mint: &'a AccountInfo<'info>,
+    pub token_2022_program: &'a AccountInfo<'info>,
+    pub memo_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> RaydiumCpSwapHarvestAccounts<'a, 'info> {
+    pub const LEN: usize = 9;
+
+    pub fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self> {
+        if accounts.len() != Self::LEN {
+            return err!(ErrorCode::InvalidDexAccounts);
+        }
+
+        Ok(Self {
+            dex_program: &accounts[0],
+            authority: &accounts[1],
+            pool_state: &accounts[2],
+            owner_lp_token: &accounts[3],
+            token_0_vault: &accounts[4],
+            token_1_vault: &accounts[5],
+            lp_mint: &accounts[6],
+            token_2022_program: &accounts[7],
+            memo_program: &accounts[8],
+        })
+    }
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> Result<Pubkey> {
+    let bytes: [u8; 32] = data
+        .get(offset..offset + 32)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(ErrorCode::InvalidDexAccounts)?;
+    Ok(Pubkey::new_from_array(bytes))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Result<u64> {
+    let bytes: [u8; 8] = data
+        .get(offset..offset + 8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(ErrorCode::InvalidDexAccounts)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+pub fn harvest_fees<'info>(
+    accounts: &HarvestFeesAccounts<'_, 'info>,
+    dex_accounts: &[AccountInfo<'info>],
+    lp_token_amount: u64,
+    lp_value_x64: &mut u128,
+    signer_seeds: &[&[u8]],
+) -> Result<u64> {
+    let dex_accounts = RaydiumCpSwapHarvestAccounts::load(dex_accounts)?;
+
+    let dex_program = accounts.dex_program;
+
+    if dex_accounts.dex_program.key() != dex_program {
+        return err!(ErrorCode::InvalidDexProgram);
+    }
+
+    let (authority, _) = Pubkey::find_program_address(&[b"vault_and_lp_mint_auth_seed".as_ref()], &dex_program);
+
+    if *dex_accounts.pool_state.owner != dex_program
+        || dex_accounts.authority.key() != authority
+        || dex_accounts.lp_mint.key() != accounts.lp_mint
+        || dex_accounts.owner_lp_token.key() != get_associated_token_address(&accounts.owner.key(), &accounts.lp_mint)
+        || dex_accounts.token_2022_program.key() != anchor_spl::token_2022::ID
+        || dex_accounts.memo_program.key() != MEMO_PROGRAM_ID
+    {
+        return err!(ErrorCode::InvalidDexAccounts);
+    }
+
+    let (token_0, token_1) = accounts.sorted();
+
+    // The reserves exclude the protocol and fund fees which are kept in the vaults
+    let (reserve_0, reserve_1, lp_supply) = {
+        let pool_state = dex_accounts.pool_state.try_borrow_data()?;
+
+        if read_pubkey(&pool_state, POOL_STATE_LP_MINT_OFFSET)? != accounts.lp_mint
+            || read_pubkey(&pool_state, POOL_STATE_TOKEN_0_MINT_OFFSET)? != token_0.mint.key()
+            || read_pubkey(&pool_state, POOL_STATE_TOKEN_0_VAULT_OFFSET)? != dex_accounts.token_0_vault.key()
+            || read_pubkey(&pool_state, POOL_STATE_TOKEN_1_VAULT_OFFSET)? != dex_accounts.token_1_vault.key()
+        {
+            return err!(ErrorCode::InvalidDexAccounts);
+        }
+
+        let protocol_fees_0 = read_u64(&pool_state, POOL_STATE_LP_SUPPLY_OFFSET + 8)?;
+        let protocol_fees_1 = read_u64(&pool_state, POOL_STATE_LP_SUPPLY_OFFSET + 16)?;
+        let fund_fees_0 = read_u64(&pool_state, POOL_STATE_LP_SUPPLY_OFFSET + 24)?;
+        let fund_fees_1 = read_u64(&pool_state, POOL_STATE_LP_SUPPLY_OFFSET + 32)?;
+
+        let vault_0_amount = read_u64(&dex_accounts.token_0_vault.try_borrow_data()?, TOKEN_ACCOUNT_AMOUNT_OFFSET)?;
+        let vault_1_amount = read_u64(&dex_accounts.token_1_vault.try_borrow_data()?, TOKEN_ACCOUNT_AMOUNT_OFFSET)?;
+
+        (
+            vault_0_amount.saturating_sub(protocol_fees_0).saturating_sub(fund_fees_0),
+            vault_1_amount.saturating_sub(protocol_fees_1).saturating_sub(fund_fees_1),
+            read_u64(&pool_state, POOL_STATE_LP_SUPPLY_OFFSET)?,
+        )
+    };
+
+    // The lp supply is sqrt(k) at pool creation, so a lp token is worth one before the first harvest
+    let last_lp_value_x64 = if *lp_value_x64 == 0 { 1 << 64 } else { *lp_value_x64 };
+    let current_lp_value_x64 = (isqrt(reserve_0 as u128 * reserve_1 as u128) << 64)
+        .checked_div(lp_supply as u128)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    if current_lp_value_x64 <= last_lp_value_x64 {
+        return Ok(0);
+    }
+
+    // Withdrawing this share of the lp tokens brings the value of the remaining ones back to the last harvest
+    let lp_token_withdrawn = ((lp_token_amount as u128 * (current_lp_value_x64 - last_lp_value_x64))
+        / current_lp_value_x64) as u64;
+
+    // The growth is kept for the next harvest until it is worth at least one lp token
+    if lp_token_withdrawn == 0 {
+        return Ok(0);
+    }
+    *lp_value_x64 = current_lp_value_x64;
+
+    let mut data = Vec::with_capacity(32);
+    sighash("global", "withdraw").serialize(&mut data)?;
+    lp_token_withdrawn.serialize(&mut data)?;
+    0u64.serialize(&mut data)?; // The amounts are bounded by the reserves read above
+    0u64.serialize(&mut data)?;
+
+    invoke_signed(
+        &Instruction {
+            program_id: dex_program,
+            accounts: vec![
+                AccountMeta::new_readonly(accounts.owner.key(), true),
+                AccountMeta::new_readonly(dex_accounts.authority.key(), false),
+                AccountMeta::new(dex_accounts.pool_state.key(), false),
+                AccountMeta::new(dex_accounts.owner_lp_token.key(), false),
+                AccountMeta::new(token_0.owner_token.key(), false),
+                AccountMeta::new(token_1.owner_token.key(), false),
+                AccountMeta::new(dex_accounts.token_0_vault.key(), false),
+                AccountMeta::new(dex_accounts.token_1_vault.key(), false),
+                AccountMeta::new_readonly(accounts.token_program.key(), false),
+                AccountMeta::new_readonly(dex_accounts.token_2022_program.key(), false),
+                AccountMeta::new_readonly(token_0.mint.key(), false),
+                AccountMeta::new_readonly(token_1.mint.key(), false),
+                AccountMeta::new(dex_accounts.lp_mint.key(), false),
+                AccountMeta::new_readonly(dex_accounts.memo_program.key(), false),
+            ],
+            data,
+        },
+        &[
+            accounts.owner.clone(),
+            dex_accounts.authority.clone(),
+            dex_accounts.pool_state.clone(),
+            dex_accounts.owner_lp_token.clone(),
+            token_0.owner_token.clone(),
+            token_1.owner_token.clone(),
+            dex_accounts.token_0_vault.clone(),
+            dex_accounts.token_1_vault.clone(),
+            accounts.token_program.clone(),
+            dex_accounts.token_2022_program.clone(),
+            token_0.mint.clone(),
+            token_1.mint.clone(),
+            dex_accounts.lp_mint.clone(),
+            dex_accounts.memo_program.clone(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    Ok(lp_token_withdrawn)
+}
@@ -29,5 +29,111 @@ pub enum ErrorCode {
     #[msg("This operation is not authorised in the current phase")]
     UnauthorisedOperationInCurrentPhase,
     #[msg("The project has already been refunded")]
-    AlreadyRefunded
+    AlreadyRefunded,
+    #[msg("The fee exceeds the maximum allowed by the program")]
+    FeeTooHigh,
+    #[msg("The account is not the fee treasury")]
+    InvalidFeeTreasury,
+    #[msg("The referral account must be provided if and only if there is a referrer")]
+    InvalidReferral,
+    #[msg("A user cannot refer itself")]
+    SelfReferral,
+    #[msg("The referral reward has already been claimed")]
+    AlreadyClaimed,
+    #[msg("The DEX accounts do not match the ones expected by the DEX of the lbp")]
+    InvalidDexAccounts,
+    #[msg("The DEX program does not match the DEX of the lbp")]
+    InvalidDexProgram,
+    #[msg("The operation overflowed")]
+    MathOverflow,
+    #[msg("The token accounts of the lbp have already been migrated")]
+    AlreadyMigrated,
+    #[msg("The pool cannot be created before its trading open time on this DEX")]
+    PoolNotOpen,
+    #[msg("The lp lock policy is not supported by the DEX of the lbp")]
+    InvalidLpLockPolicy,
+    #[msg("The liquidity pool has not been created yet")]
+    PoolNotCreated,
+    #[msg("There is nothing to claim")]
+    NothingToClaim,
+    #[msg("The trading fee split exceeds the whole of the fees")]
+    InvalidFeeSplit,
+    #[msg("The lp distribution must be a percentage")]
+    InvalidLpDistribution,
+    #[msg("The operation is not allowed by the treasury release of the lbp")]
+    InvalidTreasuryRelease,
+    #[msg("The governance rules are invalid")]
+    InvalidGovernanceRules,
+    #[msg("A proposal is already being voted")]
+    ProposalAlreadyOpen,
+    #[msg("All the treasury tranches have been released")]
+    AllTranchesReleased,
+    #[msg("The voting window of the proposal is closed")]
+    VotingClosed,
+    #[msg("The voting window of the proposal is not over yet")]
+    VotingInProgress,
+    #[msg("The proposal has already been resolved")]
+    ProposalResolved,
+    #[msg("The vesting schedule does not unlock the whole amount")]
+    InvalidVestingSchedule,
+    #[msg("The grant is not revocable")]
+    GrantNotRevocable,
+    #[msg("The grant has already been revoked")]
+    AlreadyRevoked,
+    #[msg("Not pauser authority")]
+    NotPauserAuthority,
+    #[msg("The sale is paused")]
+    Paused,
+    #[msg("The sale can only be cancelled during the fund collection")]
+    CannotCancel,
+    #[msg("The position still holds funds")]
+    PositionNotSettled,
+    #[msg("The payer does not match the one of the account")]
+    InvalidPayer,
+    #[msg("The token account is not empty")]
+    VaultNotEmpty,
+    #[msg("The lbp still has open positions, vaults or grants")]
+    LbpNotSettled,
+    #[msg("The claim deadline must be after the opening of the pool")]
+    InvalidClaimDeadline,
+    #[msg("The claim window is still open")]
+    ClaimWindowOpen,
+    #[msg("The destination does not match the one requested")]
+    InvalidDestination,
+    #[msg("Signer must be a member of the admin multisig")]
+    NotAdminMember,
+    #[msg("The admin members must be distinct and at least as many as the threshold")]
+    InvalidAdminMembers,
+    #[msg("The action must be approved through an admin proposal")]
+    MissingAdminProposal,
+    #[msg("The admin proposal does not match the action")]
+    AdminProposalMismatch,
+    #[msg("The admin proposal has already been executed")]
+    AdminProposalExecuted,
+    #[msg("The admin proposal is not approved by enough members")]
+    AdminThresholdNotReached,
+    #[msg("The member has already approved the admin proposal")]
+    AlreadyApproved,
+    #[msg("The admin proposal has been cancelled")]
+    AdminProposalCancelled,
+    #[msg("The timelock of the admin proposal has not expired yet")]
+    TimelockNotExpired,
+    #[msg("Signer must be the pending holder of the role")]
+    NotPendingRoleHolder,
+    #[msg("The role has not been granted")]
+    RoleNotGranted,
+    #[msg("The account already has the current layout")]
+    AccountUpToDate,
+    #[msg("The min cap must not exceed the max cap")]
+    InvalidCaps,
+    #[msg("The lbp can no longer be updated once it received a deposit")]
+    LbpAlreadyFunded,
+    #[msg("The vaults of the lbp must be migrated first")]
+    VaultsNotMigrated,
+    #[msg("The token account is not a vault of the lbp")]
+    InvalidVault,
+    #[msg("The signer is not the beneficiary of the grant")]
+    NotBeneficiary,
+    #[msg("The member has already voted to cancel the admin proposal")]
+    AlreadyCancelled
 }
@@ -1 +1,38 @@
+use anchor_lang::prelude::*;
 
+use crate::state::admin_proposal::AdminAction;
+use crate::state::lbp::LbpParameters;
+
+/// Emitted when the admin sweeps what is left unclaimed in an lbp after its claim deadline
+#[event]
+pub struct LbpSwept {
+    pub lbp: Pubkey,
+    /// The owner of the token accounts receiving the sweep
+    pub destination: Pubkey,
+    pub launched_token_amount: u64,
+    pub raised_token_amount: u64,
+}
+
+/// Emitted when an admin action is approved, it can be executed from its eta
+#[event]
+pub struct AdminActionQueued {
+    pub admin_proposal: Pubkey,
+    pub action: AdminAction,
+    pub target: Pubkey,
+    pub args_hash: [u8; 32],
+    pub eta: u64,
+}
+
+/// Emitted when a queued admin action is cancelled before its execution
+#[event]
+pub struct AdminActionCancelled {
+    pub admin_proposal: Pubkey,
+}
+
+/// Emitted when the parameters of an lbp are changed before it receives its first deposit
+#[event]
+pub struct LbpUpdated {
+    pub lbp: Pubkey,
+    pub old: LbpParameters,
+    pub new: LbpParameters,
+}
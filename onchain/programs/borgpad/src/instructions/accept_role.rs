@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::config::*;
+use crate::state::role::*;
+
+#[derive(Accounts)]
+pub struct AcceptRole<'info> {
+    #[account(
+        mut,
+        seeds = [b"config".as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub new_holder: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptRole>, role: Role) -> Result<()> {
+    let new_holder = ctx.accounts.new_holder.key();
+    let config: &mut Account<Config> = &mut ctx.accounts.config;
+
+    let Some(grant) = config.roles.iter_mut().find(|grant| grant.role == role) else {
+        return err!(ErrorCode::RoleNotGranted)
+    };
+
+    if grant.pending_holder != Some(new_holder) {
+        return err!(ErrorCode::NotPendingRoleHolder)
+    }
+
+    grant.holder = Some(new_holder);
+    grant.pending_holder = None;
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::AdminActionQueued;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+
+#[derive(Accounts)]
+pub struct ApproveAdminProposal<'info> {
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        constraint = config.admin_members.contains(&member.key()) @ ErrorCode::NotAdminMember
+    )]
+    pub member: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Box<Account<'info, AdminProposal>>,
+}
+
+pub fn handler(ctx: Context<ApproveAdminProposal>) -> Result<()> {
+    let admin_proposal: &mut Account<AdminProposal> = &mut ctx.accounts.admin_proposal;
+
+    if admin_proposal.executed {
+        return err!(ErrorCode::AdminProposalExecuted);
+    }
+
+    if admin_proposal.cancelled {
+        return err!(ErrorCode::AdminProposalCancelled);
+    }
+
+    if admin_proposal.approvals.contains(&ctx.accounts.member.key()) {
+        return err!(ErrorCode::AlreadyApproved);
+    }
+
+    admin_proposal.approvals.push(ctx.accounts.member.key());
+
+    let now = Clock::get()?.unix_timestamp as u64;
+    if admin_proposal.queue_if_approved(&ctx.accounts.config, now) {
+        emit!(AdminActionQueued {
+            admin_proposal: admin_proposal.key(),
+            action: admin_proposal.action.clone(),
+            target: admin_proposal.target,
+            args_hash: admin_proposal.args_hash,
+            eta: admin_proposal.eta.unwrap_or_default(),
+        });
+    }
+
+    Ok(())
+}
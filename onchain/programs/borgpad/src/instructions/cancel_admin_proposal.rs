@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::AdminActionCancelled;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+
+#[derive(Accounts)]
+pub struct CancelAdminProposal<'info> {
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        constraint = config.is_admin(admin_authority.key()) @ ErrorCode::NotAdminAuthority
+    )]
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Box<Account<'info, AdminProposal>>,
+}
+
+pub fn handler(ctx: Context<CancelAdminProposal>) -> Result<()> {
+    let admin_proposal: &mut Account<AdminProposal> = &mut ctx.accounts.admin_proposal;
+
+    if admin_proposal.executed {
+        return err!(ErrorCode::AdminProposalExecuted);
+    }
+
+    if admin_proposal.cancelled {
+        return err!(ErrorCode::AdminProposalCancelled);
+    }
+
+    // The proposer can withdraw its proposal, the other members veto it once they reach the threshold
+    if admin_proposal.cancel(&ctx.accounts.config, ctx.accounts.admin_authority.key())? {
+        emit!(AdminActionCancelled {
+            admin_proposal: admin_proposal.key(),
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+use crate::state::lbp::*;
+use crate::utils::args_hash;
+
+#[derive(Accounts)]
+pub struct CancelLbp<'info> {
+    #[account(
+        mut,
+        constraint = config.is_admin(admin_authority.key()) @ ErrorCode::NotAdminAuthority
+    )]
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"lbp".as_ref(),
+            &lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+}
+
+pub fn handler(ctx: Context<CancelLbp>, reason: CancelReason) -> Result<()> {
+    ctx.accounts.config.authorize_admin_action(
+        ctx.accounts.admin_proposal.as_deref_mut(),
+        AdminAction::CancelLbp,
+        ctx.accounts.lbp.key(),
+        args_hash(&reason)?,
+    )?;
+
+    let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
+    if lbp_data.phase != Phase::FundCollection {
+        return err!(ErrorCode::CannotCancel);
+    }
+
+    // Unlike move_to_refund_phase, the amount raised does not matter
+    lbp_data.phase = Phase::Refund;
+    lbp_data.cancel_reason = Some(reason);
+
+    Ok(())
+}
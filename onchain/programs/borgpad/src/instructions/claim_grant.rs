@@ -0,0 +1,117 @@
+use crate::errors::ErrorCode;
+use crate::state::config::*;
+use crate::state::grant::*;
+use crate::state::lbp::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct ClaimGrant<'info> {
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    #[account(
+        mut,
+        has_one = beneficiary @ ErrorCode::NotBeneficiary,
+        seeds = [
+            b"grant".as_ref(),
+            lbp.key().as_ref(),
+            & grant.index.to_le_bytes()
+        ],
+        bump = grant.bump
+    )]
+    pub grant: Box<Account<'info, Grant>>,
+
+    /// CHECK: dataless pda owning the token accounts of the lbp
+    #[account(
+        seeds = [
+            b"vault_authority".as_ref(),
+            lbp.key().as_ref()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = lbp.launched_token_mint == launched_token_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub launched_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = launched_token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub lbp_launched_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        associated_token::mint = launched_token_mint,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = token_program,
+    )]
+    pub beneficiary_launched_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimGrant>) -> Result<()> {
+    ctx.accounts.config.check_not_paused(&ctx.accounts.lbp, false)?;
+
+    let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
+    let grant_data: &mut Account<Grant> = &mut ctx.accounts.grant;
+
+    // The grants vest along with the lp tokens, from the opening of the pool
+    let now = Clock::get()?.unix_timestamp as u64;
+    let amount = grant_data.vested_amount(lbp_data.vesting_start_time, now) - grant_data.claimed;
+
+    if amount == 0 {
+        return err!(ErrorCode::NothingToClaim)
+    }
+
+    // Transfer the vested amount from lbp to beneficiary
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.lbp_launched_token_ata.to_account_info(),
+                to: ctx.accounts.beneficiary_launched_token_ata.to_account_info(),
+                mint: ctx.accounts.launched_token_mint.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            &[&[b"vault_authority", lbp_data.key().as_ref(), &[ctx.bumps.vault_authority]]],
+        ),
+        amount,
+        ctx.accounts.launched_token_mint.decimals,
+    )?;
+
+    grant_data.claimed += amount;
+    lbp_data.launched_token_granted -= amount;
+
+    Ok(())
+}
@@ -0,0 +1,177 @@
+use crate::errors::ErrorCode;
+use crate::state::config::*;
+use crate::state::lbp::*;
+use crate::state::position::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct ClaimPositionFees<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    /// CHECK: dataless pda owning the token accounts of the lbp
+    #[account(
+        seeds = [
+            b"vault_authority".as_ref(),
+            lbp.key().as_ref()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"position".as_ref(),
+            lbp.key().as_ref(),
+            position_mint.key().as_ref()
+        ],
+        bump,
+        constraint = position.lbp == lbp.key() @ ErrorCode::InvalidPosition
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    #[account(
+        associated_token::mint = position_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_position_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = launched_token_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_launched_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = raised_token_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = launched_token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub lbp_launched_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = raised_token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub lbp_raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = position.mint == position_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub position_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = lbp.launched_token_mint == launched_token_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub launched_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = lbp.raised_token_mint == raised_token_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub raised_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimPositionFees>) -> Result<()> {
+    ctx.accounts.config.check_not_paused(&ctx.accounts.lbp, false)?;
+
+    let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
+    let position_data: &mut Account<Position> = &mut ctx.accounts.position;
+
+    if ctx.accounts.user_position_ata.amount != 1 {
+        return err!(ErrorCode::DoesNotHoldPosition)
+    }
+
+    // The fees harvested since the last claim, pro rata of the amount deposited
+    let (launched_token_fees, raised_token_fees) = position_data.pending_fees(lbp_data);
+
+    position_data.launched_token_fee_checkpoint_x64 = lbp_data.launched_token_fee_per_share_x64;
+    position_data.raised_token_fee_checkpoint_x64 = lbp_data.raised_token_fee_per_share_x64;
+
+    if launched_token_fees == 0 && raised_token_fees == 0 {
+        return err!(ErrorCode::NothingToClaim)
+    }
+
+    // The shares are rounded down, so the positions never claim more than what was harvested for them
+    lbp_data.launched_token_holder_fees -= launched_token_fees;
+    lbp_data.raised_token_holder_fees -= raised_token_fees;
+
+    // Transfer fees from lbp to user
+    if launched_token_fees > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.lbp_launched_token_ata.to_account_info(),
+                    to: ctx.accounts.user_launched_token_ata.to_account_info(),
+                    mint: ctx.accounts.launched_token_mint.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                &[&[b"vault_authority", lbp_data.key().as_ref(), &[ctx.bumps.vault_authority]]],
+            ),
+            launched_token_fees,
+            ctx.accounts.launched_token_mint.decimals,
+        )?;
+    }
+
+    if raised_token_fees > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.lbp_raised_token_ata.to_account_info(),
+                    to: ctx.accounts.user_raised_token_ata.to_account_info(),
+                    mint: ctx.accounts.raised_token_mint.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                &[&[b"vault_authority", lbp_data.key().as_ref(), &[ctx.bumps.vault_authority]]],
+            ),
+            raised_token_fees,
+            ctx.accounts.raised_token_mint.decimals,
+        )?;
+    }
+
+    Ok(())
+}
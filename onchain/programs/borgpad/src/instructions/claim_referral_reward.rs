@@ -0,0 +1,170 @@
+use crate::errors::ErrorCode;
+use crate::state::config::*;
+use crate::state::lbp::*;
+use crate::state::referral::*;
+use crate::utils::mul_div;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct ClaimReferralReward<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    /// CHECK: dataless pda owning the token accounts of the lbp
+    #[account(
+        seeds = [
+            b"vault_authority".as_ref(),
+            lbp.key().as_ref()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"referral".as_ref(),
+            lbp.key().as_ref(),
+            referrer.key().as_ref()
+        ],
+        bump
+    )]
+    pub referral: Box<Account<'info, Referral>>,
+
+    #[account(
+        init_if_needed,
+        payer = referrer,
+        associated_token::mint = launched_token_mint,
+        associated_token::authority = referrer,
+        associated_token::token_program = token_program,
+    )]
+    pub referrer_launched_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = referrer,
+        associated_token::mint = raised_token_mint,
+        associated_token::authority = referrer,
+        associated_token::token_program = token_program,
+    )]
+    pub referrer_raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = launched_token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub lbp_launched_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = raised_token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub lbp_raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = lbp.launched_token_mint == launched_token_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub launched_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = lbp.raised_token_mint == raised_token_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub raised_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimReferralReward>) -> Result<()> {
+    ctx.accounts.config.check_not_paused(&ctx.accounts.lbp, false)?;
+
+    let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
+
+    // The rewards are paid from the platform fee of a successful sale, they cannot be claimed once
+    // the position holders voted an emergency refund and are left to the sweep instead
+    if lbp_data.phase != Phase::Vesting {
+        return err!(ErrorCode::UnauthorisedOperationInCurrentPhase)
+    }
+
+    if ctx.accounts.referral.claimed {
+        return err!(ErrorCode::AlreadyClaimed)
+    }
+
+    let raised_token_reward = mul_div(
+        lbp_data.raised_token_referral_reward,
+        ctx.accounts.referral.amount,
+        lbp_data.referred_token_cap,
+    );
+    let launched_token_reward = mul_div(
+        lbp_data.launched_token_referral_reward,
+        ctx.accounts.referral.amount,
+        lbp_data.referred_token_cap,
+    );
+
+    // Transfer rewards from lbp to referrer
+    if raised_token_reward > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.lbp_raised_token_ata.to_account_info(),
+                    to: ctx.accounts.referrer_raised_token_ata.to_account_info(),
+                    mint: ctx.accounts.raised_token_mint.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                &[&[b"vault_authority", lbp_data.key().as_ref(), &[ctx.bumps.vault_authority]]],
+            ),
+            raised_token_reward,
+            ctx.accounts.raised_token_mint.decimals,
+        )?;
+    }
+
+    if launched_token_reward > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.lbp_launched_token_ata.to_account_info(),
+                    to: ctx.accounts.referrer_launched_token_ata.to_account_info(),
+                    mint: ctx.accounts.launched_token_mint.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                &[&[b"vault_authority", lbp_data.key().as_ref(), &[ctx.bumps.vault_authority]]],
+            ),
+            launched_token_reward,
+            ctx.accounts.launched_token_mint.decimals,
+        )?;
+    }
+
+    ctx.accounts.referral.claimed = true;
+    lbp_data.referred_token_claimed += ctx.accounts.referral.amount;
+
+    Ok(())
+}
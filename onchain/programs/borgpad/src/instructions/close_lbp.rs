@@ -0,0 +1,41 @@
+use crate::errors::ErrorCode;
+use crate::state::lbp::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseLbp<'info> {
+    /// CHECK: receives the rent of the lbp, checked against the lbp
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = payer,
+        has_one = payer @ ErrorCode::InvalidPayer,
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+}
+
+pub fn handler(ctx: Context<CloseLbp>) -> Result<()> {
+    let lbp_data: &Account<Lbp> = &ctx.accounts.lbp;
+
+    if lbp_data.phase == Phase::FundCollection {
+        return err!(ErrorCode::UnauthorisedOperationInCurrentPhase)
+    }
+
+    // Nothing is left outstanding once every position, vault, grant and lp token is settled
+    if lbp_data.position_count > 0
+        || lbp_data.vault_count > 0
+        || lbp_data.launched_token_granted > 0
+        || lbp_data.lp_token_amount > lbp_data.lp_token_claimed
+    {
+        return err!(ErrorCode::LbpNotSettled)
+    }
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use crate::errors::ErrorCode;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+use crate::state::lbp::*;
+use crate::utils::args_hash;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, CloseAccount, Mint, TokenAccount, TokenInterface,
+};
+
+#[derive(Accounts)]
+pub struct CloseLbpVault<'info> {
+    /// The admin or the payer of the lbp
+    #[account(
+        constraint = config.is_admin(authority.key()) || lbp.payer == authority.key() @ ErrorCode::NotAdminAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// CHECK: receives the rent of the vault, checked against the lbp
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = payer @ ErrorCode::InvalidPayer,
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    /// CHECK: dataless pda owning the token accounts of the lbp
+    #[account(
+        seeds = [
+            b"vault_authority".as_ref(),
+            lbp.key().as_ref()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = lbp.launched_token_mint == mint.key() || lbp.raised_token_mint == mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<CloseLbpVault>) -> Result<()> {
+    // The payer of the lbp recovers its own rent, anyone else goes through the checks of the admin actions
+    if ctx.accounts.lbp.payer != ctx.accounts.authority.key() {
+        ctx.accounts.config.authorize_admin_action(
+            ctx.accounts.admin_proposal.as_deref_mut(),
+            AdminAction::CloseLbpVault,
+            ctx.accounts.lbp.key(),
+            args_hash(&ctx.accounts.mint.key())?,
+        )?;
+    }
+
+    let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
+
+    // The vaults are still used during the fund collection
+    if lbp_data.phase == Phase::FundCollection {
+        return err!(ErrorCode::UnauthorisedOperationInCurrentPhase)
+    }
+
+    // Once the pool is created, the vaults keep receiving the trading fees of the lp tokens held by
+    // the lbp and paying out the grants and the claims, until everything left can be swept
+    if lbp_data.pool_status == PoolStatus::PoolCreated {
+        let now = Clock::get()?.unix_timestamp as u64;
+        if now < lbp_data.claim_deadline || lbp_data.lp_token_amount > lbp_data.lp_token_claimed {
+            return err!(ErrorCode::LbpNotSettled)
+        }
+    }
+
+    if ctx.accounts.vault.amount > 0 {
+        return err!(ErrorCode::VaultNotEmpty)
+    }
+
+    close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.payer.to_account_info(),
+            authority: ctx.accounts.vault_authority.to_account_info(),
+        },
+        &[&[b"vault_authority", lbp_data.key().as_ref(), &[ctx.bumps.vault_authority]]],
+    ))?;
+
+    lbp_data.vault_count = lbp_data.vault_count
+        .checked_sub(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use crate::errors::ErrorCode;
+use crate::state::lbp::*;
+use crate::state::position::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct ClosePosition<'info> {
+    /// The holder of the position nft
+    pub user: Signer<'info>,
+
+    /// CHECK: receives the rent of the position, checked against the position
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    #[account(
+        mut,
+        close = payer,
+        has_one = payer @ ErrorCode::InvalidPosition,
+        seeds = [
+            b"position".as_ref(),
+            lbp.key().as_ref(),
+            position_mint.key().as_ref()
+        ],
+        bump = position.bump,
+        constraint = position.lbp == lbp.key() @ ErrorCode::InvalidPosition
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    #[account(
+        associated_token::mint = position_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_position_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = position.mint == position_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub position_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<ClosePosition>) -> Result<()> {
+    let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
+    let position_data: &Account<Position> = &ctx.accounts.position;
+
+    if ctx.accounts.user_position_ata.amount != 1 {
+        return err!(ErrorCode::DoesNotHoldPosition)
+    }
+
+    // The refunds close the positions themselves, so only the positions of a sale which went through
+    // can be closed, once their launched tokens and the trading fees harvested for them are claimed
+    if lbp_data.phase != Phase::Vesting || lbp_data.pool_status != PoolStatus::PoolCreated {
+        return err!(ErrorCode::UnauthorisedOperationInCurrentPhase)
+    }
+
+    if position_data.launched_token_claimed < lbp_data.launched_token_share(position_data.amount)
+        || position_data.launched_token_fee_checkpoint_x64 != lbp_data.launched_token_fee_per_share_x64
+        || position_data.raised_token_fee_checkpoint_x64 != lbp_data.raised_token_fee_per_share_x64
+    {
+        return err!(ErrorCode::PositionNotSettled)
+    }
+
+    // The position no longer shares the trading fees harvested next
+    lbp_data.raised_token_deposited -= position_data.amount;
+    lbp_data.position_count = lbp_data.position_count
+        .checked_sub(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    Ok(())
+}
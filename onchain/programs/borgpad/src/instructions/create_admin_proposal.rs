@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::AdminActionQueued;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+
+#[derive(Accounts)]
+pub struct CreateAdminProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"config".as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = config.is_admin(member.key()) @ ErrorCode::NotAdminAuthority
+    )]
+    pub member: Signer<'info>,
+
+    #[account(
+        init,
+        payer = member,
+        space = AdminProposal::LEN,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &config.admin_proposal_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub admin_proposal: Box<Account<'info, AdminProposal>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateAdminProposal>,
+    action: AdminAction,
+    target: Pubkey,
+    args_hash: [u8; 32],
+) -> Result<()> {
+    let config: &mut Account<Config> = &mut ctx.accounts.config;
+
+    ctx.accounts.admin_proposal.initialize(
+        config.admin_proposal_count,
+        ctx.accounts.member.key(),
+        action,
+        target,
+        args_hash,
+        ctx.bumps.admin_proposal,
+    );
+
+    config.admin_proposal_count += 1;
+
+    // Without multisig the proposal of the admin is approved right away
+    let now = Clock::get()?.unix_timestamp as u64;
+    let admin_proposal: &mut Account<AdminProposal> = &mut ctx.accounts.admin_proposal;
+    if admin_proposal.queue_if_approved(config, now) {
+        emit!(AdminActionQueued {
+            admin_proposal: admin_proposal.key(),
+            action: admin_proposal.action.clone(),
+            target: admin_proposal.target,
+            args_hash: admin_proposal.args_hash,
+            eta: admin_proposal.eta.unwrap_or_default(),
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use crate::errors::ErrorCode;
+use crate::state::config::*;
+use crate::state::grant::*;
+use crate::state::lbp::*;
+use crate::state::vesting_schedule::VestingSchedule;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct CreateGrant<'info> {
+    #[account(
+        mut,
+        constraint = lbp.project == project.key() @ ErrorCode::NotProject
+    )]
+    pub project: Signer<'info>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    #[account(
+        init,
+        payer = project,
+        space = Grant::LEN,
+        seeds = [
+            b"grant".as_ref(),
+            lbp.key().as_ref(),
+            & lbp.grant_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub grant: Box<Account<'info, Grant>>,
+
+    /// CHECK: dataless pda owning the token accounts of the lbp
+    #[account(
+        seeds = [
+            b"vault_authority".as_ref(),
+            lbp.key().as_ref()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = lbp.launched_token_mint == launched_token_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub launched_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = launched_token_mint,
+        associated_token::authority = project,
+        associated_token::token_program = token_program,
+    )]
+    pub project_launched_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = launched_token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub lbp_launched_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateGrant>,
+    beneficiary: Pubkey,
+    amount: u64,
+    schedule: VestingSchedule,
+    revocable: bool,
+) -> Result<()> {
+    ctx.accounts.config.check_not_paused(&ctx.accounts.lbp, false)?;
+
+    let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
+
+    // The grants are funded apart from the launched token deposit of the project, alongside the sale
+    // or once it is finalized
+    if lbp_data.phase != Phase::FundCollection && lbp_data.phase != Phase::Vesting {
+        return err!(ErrorCode::UnauthorisedOperationInCurrentPhase)
+    }
+
+    if amount == 0 {
+        return err!(ErrorCode::InvalidAmount)
+    }
+
+    schedule.validate()?;
+
+    // Transfer the granted amount from project to lbp
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.project_launched_token_ata.to_account_info(),
+                to: ctx.accounts.lbp_launched_token_ata.to_account_info(),
+                mint: ctx.accounts.launched_token_mint.to_account_info(),
+                authority: ctx.accounts.project.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.launched_token_mint.decimals,
+    )?;
+
+    lbp_data.launched_token_granted += amount;
+
+    ctx.accounts.grant.initialize(
+        lbp_data,
+        beneficiary,
+        amount,
+        schedule,
+        revocable,
+        ctx.bumps.grant,
+    );
+
+    lbp_data.grant_count += 1;
+
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use std::cmp::max;
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{burn, Burn, Mint, TokenAccount, TokenInterface};
+
+use crate::constants::POOL_CREATION_FUNDING_LAMPORTS;
+use crate::dex::{self, CreatePoolAccounts};
+use crate::errors::ErrorCode;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+use crate::state::lbp::*;
+use crate::utils::args_hash;
+
+#[derive(Accounts)]
+pub struct CreatePool<'info> {
+    /// The admin, the holder of the sale operator role or the manager of the lbp
+    #[account(
+        mut,
+        constraint = config.is_lbp_admin(&lbp, admin_authority.key()) @ ErrorCode::NotAdminAuthority
+    )]
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    /// CHECK: dataless pda owning the token accounts of the lbp
+    #[account(
+        mut,
+        seeds = [
+            b"vault_authority".as_ref(),
+            lbp.key().as_ref()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = launched_token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub launched_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = raised_token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = lbp.launched_token_mint == launched_token_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub launched_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = lbp.raised_token_mint == raised_token_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub raised_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CreatePool<'info>>) -> Result<()> {
+    ctx.accounts.config.check_not_paused(&ctx.accounts.lbp, false)?;
+
+    ctx.accounts.config.authorize_lbp_action(
+        &ctx.accounts.lbp,
+        ctx.accounts.admin_authority.key(),
+        ctx.accounts.admin_proposal.as_deref_mut(),
+        AdminAction::CreatePool,
+        args_hash(&())?,
+    )?;
+
+    let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
+
+    if lbp_data.phase != Phase::Vesting {
+        return err!(ErrorCode::UnauthorisedOperationInCurrentPhase)
+    }
+
+    // The pool creation can be retried until it succeeds, and is a no-op once done
+    if lbp_data.pool_status == PoolStatus::PoolCreated {
+        return Ok(());
+    }
+
+    // Platform fees, the launched tokens vested to the positions and the project treasury are not part
+    // of the liquidity
+    let launched_token_amount = lbp_data.launched_token_cap - lbp_data.launched_token_fee - lbp_data.launched_token_reward;
+    let raised_token_amount = lbp_data.raised_token_cap - lbp_data.raised_token_fee - lbp_data.raised_token_treasury;
+
+    // The pool is created by the vault authority as the DEXes require the creator to not carry data
+    // It pays the pool creation fees and rents, so it is funded by the admin beforehand
+    let funding = POOL_CREATION_FUNDING_LAMPORTS.saturating_sub(ctx.accounts.vault_authority.lamports());
+    if funding > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.admin_authority.to_account_info(),
+                    to: ctx.accounts.vault_authority.to_account_info(),
+                },
+            ),
+            funding,
+        )?;
+    }
+
+    let lbp_key = lbp_data.key();
+    let signer_seeds: &[&[u8]] = &[b"vault_authority", lbp_key.as_ref(), &[ctx.bumps.vault_authority]];
+
+    let lp_token = dex::create_pool(
+        &lbp_data.dex,
+        &CreatePoolAccounts {
+            lbp: lbp_data.key(),
+            dex_program: ctx.accounts.config.dex_program(&lbp_data.dex),
+            creator: &ctx.accounts.vault_authority.to_account_info(),
+            launched_token_mint: &ctx.accounts.launched_token_mint.to_account_info(),
+            raised_token_mint: &ctx.accounts.raised_token_mint.to_account_info(),
+            creator_launched_token: &ctx.accounts.launched_token_ata.to_account_info(),
+            creator_raised_token: &ctx.accounts.raised_token_ata.to_account_info(),
+            token_program: &ctx.accounts.token_program.to_account_info(),
+            associated_token_program: &ctx.accounts.associated_token_program.to_account_info(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+            rent: &ctx.accounts.rent.to_account_info(),
+        },
+        ctx.remaining_accounts,
+        launched_token_amount,
+        raised_token_amount,
+        lbp_data.pool_open_time,
+        signer_seeds,
+    )?;
+
+    // The lp tokens stay in the custody of the vault authority until released to the project
+    let lp_token_amount = TokenAccount::try_deserialize(&mut &lp_token.token_account.try_borrow_data()?[..])?.amount;
+    lbp_data.lp_mint = lp_token.mint.key();
+
+    // A position nft cannot be burnt without withdrawing the liquidity, it is kept locked instead
+    if lbp_data.lp_lock_policy == LpLockPolicy::Burn && lp_token.fungible {
+        burn(
+            CpiContext::new_with_signer(
+                lp_token.token_program,
+                Burn {
+                    mint: lp_token.mint,
+                    from: lp_token.token_account,
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                &[signer_seeds],
+            ),
+            lp_token_amount,
+        )?;
+    } else {
+        lbp_data.lp_token_amount = lp_token_amount;
+    }
+
+    lbp_data.pool_status = PoolStatus::PoolCreated;
+    // The cliff starts when the trading opens on the pool
+    lbp_data.vesting_start_time = max(Clock::get()?.unix_timestamp as u64, lbp_data.pool_open_time);
+
+    Ok(())
+}
@@ -0,0 +1,208 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::errors::ErrorCode;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+use crate::state::lbp::*;
+use crate::utils::{args_hash, bps_of, mul_div};
+
+#[derive(Accounts)]
+pub struct FinalizeSale<'info> {
+    /// The admin, the holder of the sale operator role or the manager of the lbp
+    #[account(
+        mut,
+        constraint = config.is_lbp_admin(&lbp, admin_authority.key()) @ ErrorCode::NotAdminAuthority
+    )]
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+        b"lbp".as_ref(),
+        & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    /// CHECK: dataless pda owning the token accounts of the lbp
+    #[account(
+        seeds = [
+            b"vault_authority".as_ref(),
+            lbp.key().as_ref()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = launched_token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub launched_token_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = raised_token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub raised_token_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = lbp.launched_token_mint == launched_token_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub launched_token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        constraint = lbp.raised_token_mint == raised_token_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub raised_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: only used as the authority of the fee treasury atas
+    #[account(
+        constraint = config.fee_treasury == fee_treasury.key() @ ErrorCode::InvalidFeeTreasury
+    )]
+    pub fee_treasury: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin_authority,
+        associated_token::mint = launched_token_mint,
+        associated_token::authority = fee_treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub fee_treasury_launched_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin_authority,
+        associated_token::mint = raised_token_mint,
+        associated_token::authority = fee_treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub fee_treasury_raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<FinalizeSale>) -> Result<()> {
+    ctx.accounts.config.authorize_lbp_action(
+        &ctx.accounts.lbp,
+        ctx.accounts.admin_authority.key(),
+        ctx.accounts.admin_proposal.as_deref_mut(),
+        AdminAction::FinalizeSale,
+        args_hash(&())?,
+    )?;
+
+    let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
+
+    // The sale is settled against the recorded deposits, anything sent directly to the vaults is
+    // left to the sweep
+    if lbp_data.phase != Phase::FundCollection
+        || lbp_data.raised_token_deposited < lbp_data.raised_token_min_cap
+        || lbp_data.launched_token_deposited != lbp_data.launched_token_cap
+    {
+        return err!(ErrorCode::InvalidPhaseChange);
+    }
+
+    lbp_data.phase = Phase::Vesting;
+    lbp_data.raised_token_cap = lbp_data.raised_token_deposited;
+    // The platform fees are taken on the recorded deposits, so that donations do not inflate them
+    lbp_data.raised_token_fee = bps_of(lbp_data.raised_token_deposited, ctx.accounts.config.raised_token_fee_bps);
+    lbp_data.launched_token_fee = bps_of(lbp_data.launched_token_deposited, ctx.accounts.config.launched_token_fee_bps);
+
+    // The launched tokens not used for the liquidity are kept in the lbp and vested to the positions
+    lbp_data.launched_token_reward = mul_div(
+        lbp_data.launched_token_deposited - lbp_data.launched_token_fee,
+        100 - lbp_data.launched_token_lp_distribution as u64,
+        100,
+    );
+
+    // The raised tokens not used for the liquidity are kept in the lbp for the project treasury
+    lbp_data.raised_token_treasury = mul_div(
+        lbp_data.raised_token_cap - lbp_data.raised_token_fee,
+        100 - lbp_data.raised_token_lp_distribution as u64,
+        100,
+    );
+
+    // The referral rewards are kept in the lbp until claimed
+    lbp_data.raised_token_referral_reward = mul_div(
+        bps_of(lbp_data.raised_token_fee, ctx.accounts.config.referral_raised_token_fee_bps),
+        lbp_data.referred_token_cap,
+        lbp_data.raised_token_cap,
+    );
+    lbp_data.launched_token_referral_reward = mul_div(
+        bps_of(lbp_data.launched_token_fee, ctx.accounts.config.referral_launched_token_fee_bps),
+        lbp_data.referred_token_cap,
+        lbp_data.raised_token_cap,
+    );
+
+    let raised_token_treasury_fee = lbp_data.raised_token_fee - lbp_data.raised_token_referral_reward;
+    let launched_token_treasury_fee = lbp_data.launched_token_fee - lbp_data.launched_token_referral_reward;
+
+    // Transfer platform fees from lbp to fee treasury
+    if raised_token_treasury_fee > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.raised_token_ata.to_account_info(),
+                    to: ctx.accounts.fee_treasury_raised_token_ata.to_account_info(),
+                    mint: ctx.accounts.raised_token_mint.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                &[&[b"vault_authority", lbp_data.key().as_ref(), &[ctx.bumps.vault_authority]]],
+            ),
+            raised_token_treasury_fee,
+            ctx.accounts.raised_token_mint.decimals,
+        )?;
+    }
+
+    if launched_token_treasury_fee > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.launched_token_ata.to_account_info(),
+                    to: ctx.accounts.fee_treasury_launched_token_ata.to_account_info(),
+                    mint: ctx.accounts.launched_token_mint.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                &[&[b"vault_authority", lbp_data.key().as_ref(), &[ctx.bumps.vault_authority]]],
+            ),
+            launched_token_treasury_fee,
+            ctx.accounts.launched_token_mint.decimals,
+        )?;
+    }
+
+    lbp_data.pool_status = PoolStatus::PoolPending;
+
+    Ok(())
+}
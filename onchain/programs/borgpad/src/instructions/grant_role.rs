@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+use crate::state::role::*;
+use crate::utils::args_hash;
+
+#[derive(Accounts)]
+pub struct GrantRole<'info> {
+    #[account(
+        mut,
+        seeds = [b"config".as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = config.is_admin(admin_authority.key()) @ ErrorCode::NotAdminAuthority
+    )]
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
+}
+
+pub fn handler(ctx: Context<GrantRole>, role: Role, new_holder: Pubkey) -> Result<()> {
+    ctx.accounts.config.authorize_admin_action(
+        ctx.accounts.admin_proposal.as_deref_mut(),
+        AdminAction::GrantRole,
+        Pubkey::default(),
+        args_hash(&(role, new_holder))?,
+    )?;
+
+    // The current holder keeps the role until the new one accepts it
+    let config: &mut Account<Config> = &mut ctx.accounts.config;
+    match config.roles.iter_mut().find(|grant| grant.role == role) {
+        Some(grant) => grant.pending_holder = Some(new_holder),
+        None => config.roles.push(RoleGrant {
+            role,
+            holder: None,
+            pending_holder: Some(new_holder),
+        }),
+    }
+
+    Ok(())
+}
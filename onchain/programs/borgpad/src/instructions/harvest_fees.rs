@@ -0,0 +1,262 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::dex::{self, HarvestFeesAccounts};
+use crate::errors::ErrorCode;
+use crate::state::config::*;
+use crate::state::lbp::*;
+use crate::utils::bps_of;
+
+#[derive(Accounts)]
+pub struct HarvestFees<'info> {
+    /// Anyone can harvest the fees, the payer only funds the creation of the missing token accounts
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    /// CHECK: dataless pda owning the token accounts of the lbp
+    #[account(
+        seeds = [
+            b"vault_authority".as_ref(),
+            lbp.key().as_ref()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = launched_token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub lbp_launched_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = raised_token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub lbp_raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: only used as the authority of the project atas
+    #[account(
+        constraint = lbp.project == project.key() @ ErrorCode::NotProject
+    )]
+    pub project: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = launched_token_mint,
+        associated_token::authority = project,
+        associated_token::token_program = token_program,
+    )]
+    pub project_launched_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = raised_token_mint,
+        associated_token::authority = project,
+        associated_token::token_program = token_program,
+    )]
+    pub project_raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: only used as the authority of the fee treasury atas
+    #[account(
+        constraint = config.fee_treasury == fee_treasury.key() @ ErrorCode::InvalidFeeTreasury
+    )]
+    pub fee_treasury: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = launched_token_mint,
+        associated_token::authority = fee_treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub fee_treasury_launched_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = raised_token_mint,
+        associated_token::authority = fee_treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub fee_treasury_raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = lbp.launched_token_mint == launched_token_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub launched_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = lbp.raised_token_mint == raised_token_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub raised_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, HarvestFees<'info>>) -> Result<()> {
+    ctx.accounts.config.check_not_paused(&ctx.accounts.lbp, false)?;
+
+    let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
+
+    if lbp_data.pool_status != PoolStatus::PoolCreated {
+        return err!(ErrorCode::PoolNotCreated)
+    }
+
+    let lbp_key = lbp_data.key();
+    let signer_seeds: &[&[u8]] = &[b"vault_authority", lbp_key.as_ref(), &[ctx.bumps.vault_authority]];
+
+    // The fees are collected in the lbp atas, and measured by the change of their balances
+    let launched_token_balance = ctx.accounts.lbp_launched_token_ata.amount;
+    let raised_token_balance = ctx.accounts.lbp_raised_token_ata.amount;
+    let mut lp_value_x64 = lbp_data.lp_value_x64;
+
+    let lp_token_withdrawn = dex::harvest_fees(
+        &lbp_data.dex,
+        &HarvestFeesAccounts {
+            lbp: lbp_key,
+            dex_program: ctx.accounts.config.dex_program(&lbp_data.dex),
+            lp_mint: lbp_data.lp_mint,
+            owner: &ctx.accounts.vault_authority.to_account_info(),
+            launched_token_mint: &ctx.accounts.launched_token_mint.to_account_info(),
+            raised_token_mint: &ctx.accounts.raised_token_mint.to_account_info(),
+            owner_launched_token: &ctx.accounts.lbp_launched_token_ata.to_account_info(),
+            owner_raised_token: &ctx.accounts.lbp_raised_token_ata.to_account_info(),
+            token_program: &ctx.accounts.token_program.to_account_info(),
+        },
+        ctx.remaining_accounts,
+        lbp_data.lp_token_amount - lbp_data.lp_token_claimed,
+        &mut lp_value_x64,
+        signer_seeds,
+    )?;
+    lbp_data.lp_value_x64 = lp_value_x64;
+    lbp_data.lp_token_amount -= lp_token_withdrawn;
+
+    ctx.accounts.lbp_launched_token_ata.reload()?;
+    ctx.accounts.lbp_raised_token_ata.reload()?;
+    let launched_token_fees = ctx.accounts.lbp_launched_token_ata.amount - launched_token_balance;
+    let raised_token_fees = ctx.accounts.lbp_raised_token_ata.amount - raised_token_balance;
+
+    let split = &lbp_data.trading_fee_split;
+    let launched_token_project_fees = bps_of(launched_token_fees, split.project_bps);
+    let mut launched_token_treasury_fees = bps_of(launched_token_fees, split.treasury_bps);
+    let raised_token_project_fees = bps_of(raised_token_fees, split.project_bps);
+    let mut raised_token_treasury_fees = bps_of(raised_token_fees, split.treasury_bps);
+
+    // The part of the holders stays in the lbp atas until claimed with the positions, shared among the
+    // positions still open, or goes to the fee treasury once they are all closed
+    let launched_token_holder_fees = launched_token_fees - launched_token_project_fees - launched_token_treasury_fees;
+    let raised_token_holder_fees = raised_token_fees - raised_token_project_fees - raised_token_treasury_fees;
+
+    if lbp_data.raised_token_deposited > 0 {
+        lbp_data.launched_token_fee_per_share_x64 +=
+            ((launched_token_holder_fees as u128) << 64) / lbp_data.raised_token_deposited as u128;
+        lbp_data.raised_token_fee_per_share_x64 +=
+            ((raised_token_holder_fees as u128) << 64) / lbp_data.raised_token_deposited as u128;
+        lbp_data.launched_token_holder_fees += launched_token_holder_fees;
+        lbp_data.raised_token_holder_fees += raised_token_holder_fees;
+    } else {
+        launched_token_treasury_fees += launched_token_holder_fees;
+        raised_token_treasury_fees += raised_token_holder_fees;
+    }
+
+    // Transfer fees from lbp to project and fee treasury
+    if launched_token_project_fees > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.lbp_launched_token_ata.to_account_info(),
+                    to: ctx.accounts.project_launched_token_ata.to_account_info(),
+                    mint: ctx.accounts.launched_token_mint.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                &[signer_seeds],
+            ),
+            launched_token_project_fees,
+            ctx.accounts.launched_token_mint.decimals,
+        )?;
+    }
+
+    if raised_token_project_fees > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.lbp_raised_token_ata.to_account_info(),
+                    to: ctx.accounts.project_raised_token_ata.to_account_info(),
+                    mint: ctx.accounts.raised_token_mint.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                &[signer_seeds],
+            ),
+            raised_token_project_fees,
+            ctx.accounts.raised_token_mint.decimals,
+        )?;
+    }
+
+    if launched_token_treasury_fees > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.lbp_launched_token_ata.to_account_info(),
+                    to: ctx.accounts.fee_treasury_launched_token_ata.to_account_info(),
+                    mint: ctx.accounts.launched_token_mint.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                &[signer_seeds],
+            ),
+            launched_token_treasury_fees,
+            ctx.accounts.launched_token_mint.decimals,
+        )?;
+    }
+
+    if raised_token_treasury_fees > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.lbp_raised_token_ata.to_account_info(),
+                    to: ctx.accounts.fee_treasury_raised_token_ata.to_account_info(),
+                    mint: ctx.accounts.raised_token_mint.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                &[signer_seeds],
+            ),
+            raised_token_treasury_fees,
+            ctx.accounts.raised_token_mint.decimals,
+        )?;
+    }
+
+    Ok(())
+}
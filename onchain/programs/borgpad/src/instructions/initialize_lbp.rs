@@ -1,6 +1,9 @@
 use crate::errors::ErrorCode;
+use crate::state::admin_proposal::*;
 use crate::state::config::*;
+use crate::state::role::Role;
 use crate::state::lbp::*;
+use crate::utils::args_hash;
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
@@ -8,12 +11,23 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 #[derive(Accounts)]
 #[instruction(lbp_initialize: LbpInitializeData)]
 pub struct InitializeLbp<'info> {
+    /// The admin, or the holder of the sale operator role
     #[account(
         mut,
-        constraint = config.admin_authority == admin_authority.key() @ ErrorCode::NotAdminAuthority
+        constraint = config.is_admin_or(Role::SaleOperator, admin_authority.key()) @ ErrorCode::NotAdminAuthority
     )]
     pub admin_authority: Signer<'info>,
 
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
+
     #[account(
         seeds = [b"config".as_ref()],
         bump
@@ -32,11 +46,21 @@ pub struct InitializeLbp<'info> {
     )]
     pub lbp: Box<Account<'info, Lbp>>,
 
+    /// CHECK: dataless pda owning the token accounts of the lbp
+    #[account(
+        seeds = [
+            b"vault_authority".as_ref(),
+            lbp.key().as_ref()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
     #[account(
         init_if_needed,
         payer = admin_authority,
         associated_token::mint = raised_token_mint,
-        associated_token::authority = lbp,
+        associated_token::authority = vault_authority,
         associated_token::token_program = token_program,
     )]
     pub lbp_raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
@@ -45,7 +69,7 @@ pub struct InitializeLbp<'info> {
         init_if_needed,
         payer = admin_authority,
         associated_token::mint = launched_token_mint,
-        associated_token::authority = lbp,
+        associated_token::authority = vault_authority,
         associated_token::token_program = token_program,
     )]
     pub lbp_launched_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
@@ -70,9 +94,21 @@ pub struct InitializeLbp<'info> {
 }
 
 pub fn handler(ctx: Context<InitializeLbp>, lbp_initialize: LbpInitializeData) -> Result<()> {
+    ctx.accounts.config.authorize_role_or_admin_action(
+        Role::SaleOperator,
+        ctx.accounts.admin_authority.key(),
+        ctx.accounts.admin_proposal.as_deref_mut(),
+        AdminAction::InitializeLbp,
+        ctx.accounts.lbp.key(),
+        args_hash(&lbp_initialize)?,
+    )?;
+
+    LbpParameters::from(&lbp_initialize).validate()?;
+
     let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
     lbp_data.initialize(
         lbp_initialize,
+        ctx.accounts.admin_authority.key(),
         ctx.accounts.lbp_launched_token_ata.key(),
         ctx.accounts.lbp_raised_token_ata.key(),
         ctx.bumps.lbp,
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::config::*;
+use crate::state::legacy::LegacyConfigV0;
+use crate::state::versioning::upgrade_account;
+
+/// Upgrades the config account to the current layout
+/// It must be migrated first, as the other instructions, the migrations included, read the config
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub admin_authority: Signer<'info>,
+
+    /// CHECK: the config is of the legacy layout, it is read as such and then upgraded
+    #[account(
+        mut,
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateConfig>) -> Result<()> {
+    let config_info = ctx.accounts.config.to_account_info();
+    let legacy_config = LegacyConfigV0::load(&config_info)?;
+
+    if legacy_config.admin_authority != ctx.accounts.admin_authority.key() {
+        return err!(ErrorCode::NotAdminAuthority);
+    }
+
+    upgrade_account::<Config>(
+        &config_info,
+        &ctx.accounts.admin_authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        |config| {
+            legacy_config.upgrade(config);
+            Ok(())
+        },
+    )?;
+
+    Ok(())
+}
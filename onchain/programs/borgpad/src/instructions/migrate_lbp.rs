@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::{token, token_2022};
+
+use crate::errors::ErrorCode;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+use crate::state::legacy::LegacyLbpV0;
+use crate::state::lbp::*;
+use crate::state::versioning::upgrade_account;
+use crate::utils::args_hash;
+
+/// Upgrades an lbp account to the current layout
+/// Its vaults must be migrated first, and its positions after it
+#[derive(Accounts)]
+pub struct MigrateLbp<'info> {
+    #[account(
+        mut,
+        constraint = config.is_admin(admin_authority.key()) @ ErrorCode::NotAdminAuthority
+    )]
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// CHECK: the lbp is of the legacy layout, it is read as such and then upgraded
+    #[account(mut)]
+    pub lbp: UncheckedAccount<'info>,
+
+    pub lbp_launched_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub lbp_raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateLbp>) -> Result<()> {
+    ctx.accounts.config.authorize_admin_action(
+        ctx.accounts.admin_proposal.as_deref_mut(),
+        AdminAction::MigrateLbp,
+        ctx.accounts.lbp.key(),
+        args_hash(&())?,
+    )?;
+
+    let lbp_info = ctx.accounts.lbp.to_account_info();
+    let legacy_lbp = LegacyLbpV0::load(&lbp_info)?;
+
+    // The vaults are owned by the vault authority once migrated
+    let vault_authority = Pubkey::find_program_address(
+        &[b"vault_authority", lbp_info.key.as_ref()],
+        &crate::ID,
+    ).0;
+    let is_vault = |ata: Pubkey, mint: Pubkey| {
+        [token::ID, token_2022::ID].iter().any(|token_program| {
+            ata == get_associated_token_address_with_program_id(&vault_authority, &mint, token_program)
+        })
+    };
+    if !is_vault(legacy_lbp.launched_token_ata, legacy_lbp.launched_token_mint)
+        || !is_vault(legacy_lbp.raised_token_ata, legacy_lbp.raised_token_mint)
+    {
+        return err!(ErrorCode::VaultsNotMigrated);
+    }
+
+    if ctx.accounts.lbp_launched_token_ata.key() != legacy_lbp.launched_token_ata
+        || ctx.accounts.lbp_raised_token_ata.key() != legacy_lbp.raised_token_ata
+    {
+        return err!(ErrorCode::InvalidVault);
+    }
+
+    // The legacy program recorded no deposit, they are taken from the vaults
+    let launched_token_deposited = ctx.accounts.lbp_launched_token_ata.amount;
+    let raised_token_deposited = ctx.accounts.lbp_raised_token_ata.amount;
+
+    // The admin funds the growth of the lbp, and is refunded its rent once it is closed
+    let payer = ctx.accounts.admin_authority.key();
+    upgrade_account::<Lbp>(
+        &lbp_info,
+        &ctx.accounts.admin_authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        |lbp| {
+            legacy_lbp.upgrade(lbp, payer);
+            lbp.launched_token_deposited = launched_token_deposited;
+            lbp.raised_token_deposited = raised_token_deposited;
+            Ok(())
+        },
+    )?;
+
+    Ok(())
+}
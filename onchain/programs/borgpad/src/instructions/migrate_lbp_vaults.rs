@@ -0,0 +1,189 @@
+use crate::errors::ErrorCode;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+use crate::state::legacy::LegacyLbpV0;
+use crate::utils::args_hash;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+/// Moves the tokens of an lbp created when its token accounts were owned by the lbp itself
+/// to the token accounts owned by its vault authority
+/// Such an lbp still has the legacy layout, so it must be migrated before the lbp itself
+#[derive(Accounts)]
+pub struct MigrateLbpVaults<'info> {
+    #[account(
+        mut,
+        constraint = config.is_admin(admin_authority.key()) @ ErrorCode::NotAdminAuthority
+    )]
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// CHECK: an lbp of the legacy layout, deserialized and checked in the handler
+    #[account(mut)]
+    pub lbp: UncheckedAccount<'info>,
+
+    /// CHECK: dataless pda owning the token accounts of the lbp
+    #[account(
+        seeds = [
+            b"vault_authority".as_ref(),
+            lbp.key().as_ref()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = launched_token_mint,
+        associated_token::authority = lbp,
+        associated_token::token_program = token_program,
+    )]
+    pub legacy_launched_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = raised_token_mint,
+        associated_token::authority = lbp,
+        associated_token::token_program = token_program,
+    )]
+    pub legacy_raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin_authority,
+        associated_token::mint = launched_token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub launched_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin_authority,
+        associated_token::mint = raised_token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub launched_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub raised_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateLbpVaults>) -> Result<()> {
+    ctx.accounts.config.authorize_admin_action(
+        ctx.accounts.admin_proposal.as_deref_mut(),
+        AdminAction::MigrateLbpVaults,
+        ctx.accounts.lbp.key(),
+        args_hash(&())?,
+    )?;
+
+    let lbp_info = ctx.accounts.lbp.to_account_info();
+    let mut lbp_data = LegacyLbpV0::load(&lbp_info)?;
+
+    let uid = lbp_data.uid.to_le_bytes();
+    let lbp_seeds: &[&[u8]] = &[b"lbp", &uid, &[lbp_data.bump]];
+    if Pubkey::create_program_address(lbp_seeds, &crate::ID) != Ok(lbp_info.key()) {
+        return Err(anchor_lang::error::ErrorCode::ConstraintSeeds.into());
+    }
+
+    if lbp_data.launched_token_mint != ctx.accounts.launched_token_mint.key()
+        || lbp_data.raised_token_mint != ctx.accounts.raised_token_mint.key()
+    {
+        return err!(ErrorCode::InvalidMint);
+    }
+
+    if lbp_data.launched_token_ata != ctx.accounts.legacy_launched_token_ata.key()
+        || lbp_data.raised_token_ata != ctx.accounts.legacy_raised_token_ata.key()
+    {
+        return err!(ErrorCode::AlreadyMigrated);
+    }
+
+    // Transfer funds from the legacy atas to the vault authority atas
+    if ctx.accounts.legacy_launched_token_ata.amount > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.legacy_launched_token_ata.to_account_info(),
+                    to: ctx.accounts.launched_token_ata.to_account_info(),
+                    mint: ctx.accounts.launched_token_mint.to_account_info(),
+                    authority: lbp_info.clone(),
+                },
+                &[lbp_seeds],
+            ),
+            ctx.accounts.legacy_launched_token_ata.amount,
+            ctx.accounts.launched_token_mint.decimals,
+        )?;
+    }
+
+    if ctx.accounts.legacy_raised_token_ata.amount > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.legacy_raised_token_ata.to_account_info(),
+                    to: ctx.accounts.raised_token_ata.to_account_info(),
+                    mint: ctx.accounts.raised_token_mint.to_account_info(),
+                    authority: lbp_info.clone(),
+                },
+                &[lbp_seeds],
+            ),
+            ctx.accounts.legacy_raised_token_ata.amount,
+            ctx.accounts.raised_token_mint.decimals,
+        )?;
+    }
+
+    // Close the legacy atas
+    close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.legacy_launched_token_ata.to_account_info(),
+            destination: ctx.accounts.admin_authority.to_account_info(),
+            authority: lbp_info.clone(),
+        },
+        &[lbp_seeds],
+    ))?;
+
+    close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.legacy_raised_token_ata.to_account_info(),
+            destination: ctx.accounts.admin_authority.to_account_info(),
+            authority: lbp_info.clone(),
+        },
+        &[lbp_seeds],
+    ))?;
+
+    lbp_data.launched_token_ata = ctx.accounts.launched_token_ata.key();
+    lbp_data.raised_token_ata = ctx.accounts.raised_token_ata.key();
+    lbp_data.save(&lbp_info)?;
+
+    Ok(())
+}
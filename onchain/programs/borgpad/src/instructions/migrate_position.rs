@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+use crate::state::legacy::LegacyPositionV0;
+use crate::state::lbp::*;
+use crate::state::position::*;
+use crate::state::versioning::upgrade_account;
+use crate::utils::args_hash;
+
+/// Upgrades a position account to the current layout
+/// Its lbp must be migrated first, as it counts the positions migrated
+#[derive(Accounts)]
+pub struct MigratePosition<'info> {
+    #[account(
+        mut,
+        constraint = config.is_admin(admin_authority.key()) @ ErrorCode::NotAdminAuthority
+    )]
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    /// CHECK: the position is of the legacy layout, it is read as such and then upgraded
+    #[account(mut)]
+    pub position: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigratePosition>) -> Result<()> {
+    ctx.accounts.config.authorize_admin_action(
+        ctx.accounts.admin_proposal.as_deref_mut(),
+        AdminAction::MigratePosition,
+        ctx.accounts.lbp.key(),
+        args_hash(&ctx.accounts.position.key())?,
+    )?;
+
+    let position_info = ctx.accounts.position.to_account_info();
+    let legacy_position = LegacyPositionV0::load(&position_info)?;
+
+    if legacy_position.lbp != ctx.accounts.lbp.key() {
+        return err!(ErrorCode::InvalidPosition);
+    }
+
+    // The admin funds the growth of the position, and is refunded its rent once it is closed
+    let payer = ctx.accounts.admin_authority.key();
+    upgrade_account::<Position>(
+        &position_info,
+        &ctx.accounts.admin_authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        |position| {
+            legacy_position.upgrade(position, payer);
+            Ok(())
+        },
+    )?;
+
+    ctx.accounts.lbp.position_count += 1;
+
+    Ok(())
+}
@@ -3,9 +3,49 @@ pub mod user_deposit;
 pub mod initialize;
 pub mod initialize_lbp;
 pub mod move_to_refund_phase;
-pub mod move_to_vesting_phase;
+pub mod finalize_sale;
+pub mod create_pool;
 pub mod project_deposit;
 pub mod project_refund;
 pub mod user_refund;
 pub mod nominate_new_admin_authority;
 pub mod set_whitelist_authority;
+pub mod set_platform_fees;
+pub mod set_referral_fees;
+pub mod claim_referral_reward;
+pub mod set_dex_programs;
+pub mod migrate_lbp_vaults;pub mod project_claim_lp;
+pub mod harvest_fees;
+pub mod claim_position_fees;
+pub mod user_claim;
+pub mod project_claim_treasury;
+pub mod request_tranche;
+pub mod vote_proposal;
+pub mod resolve_proposal;
+pub mod propose_emergency_refund;
+pub mod create_grant;
+pub mod claim_grant;
+pub mod revoke_grant;
+pub mod set_paused;
+pub mod set_lbp_paused;
+pub mod cancel_lbp;
+pub mod close_position;
+pub mod close_lbp_vault;
+pub mod close_lbp;
+pub mod refund_position;
+pub mod sweep_lbp;
+pub mod set_admin_members;
+pub mod create_admin_proposal;
+pub mod approve_admin_proposal;
+pub mod cancel_admin_proposal;
+pub mod set_admin_timelock_delay;
+pub mod grant_role;
+pub mod accept_role;
+pub mod revoke_role;
+pub mod set_lbp_manager;
+pub mod set_lbp_whitelist_authority;
+pub mod migrate_config;
+pub mod migrate_lbp;
+pub mod migrate_position;
+pub mod update_lbp;
+pub mod reclaim_pool_funding;
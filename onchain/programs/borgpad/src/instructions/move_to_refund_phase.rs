@@ -4,17 +4,30 @@ use anchor_spl::token_interface::{
 };
 
 use crate::errors::ErrorCode;
+use crate::state::admin_proposal::*;
 use crate::state::config::*;
 use crate::state::lbp::*;
+use crate::utils::args_hash;
 
 #[derive(Accounts)]
 pub struct MoveToRefundPhase<'info> {
+    /// The admin, the holder of the sale operator role or the manager of the lbp
     #[account(
         mut,
-        constraint = config.admin_authority == admin_authority.key() @ ErrorCode::NotAdminAuthority
+        constraint = config.is_lbp_admin(&lbp, admin_authority.key()) @ ErrorCode::NotAdminAuthority
     )]
     pub admin_authority: Signer<'info>,
 
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
+
     #[account(
         seeds = [b"config".as_ref()],
         bump
@@ -31,10 +44,20 @@ pub struct MoveToRefundPhase<'info> {
     )]
     pub lbp: Box<Account<'info, Lbp>>,
 
+    /// CHECK: dataless pda owning the token accounts of the lbp
+    #[account(
+        seeds = [
+            b"vault_authority".as_ref(),
+            lbp.key().as_ref()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
     #[account(
         mut,
         associated_token::mint = launched_token_mint,
-        associated_token::authority = lbp,
+        associated_token::authority = vault_authority,
         associated_token::token_program = token_program,
     )]
     pub launched_token_ata: InterfaceAccount<'info, TokenAccount>,
@@ -42,7 +65,7 @@ pub struct MoveToRefundPhase<'info> {
     #[account(
         mut,
         associated_token::mint = raised_token_mint,
-        associated_token::authority = lbp,
+        associated_token::authority = vault_authority,
         associated_token::token_program = token_program,
     )]
     pub raised_token_ata: InterfaceAccount<'info, TokenAccount>,
@@ -63,9 +86,18 @@ pub struct MoveToRefundPhase<'info> {
 }
 
 pub fn handler(ctx: Context<MoveToRefundPhase>) -> Result<()> {
+    ctx.accounts.config.authorize_lbp_action(
+        &ctx.accounts.lbp,
+        ctx.accounts.admin_authority.key(),
+        ctx.accounts.admin_proposal.as_deref_mut(),
+        AdminAction::MoveToRefundPhase,
+        args_hash(&())?,
+    )?;
+
     let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
+    // The recorded deposits are checked rather than the vault, to which anyone can transfer
     if lbp_data.phase != Phase::FundCollection
-        || ctx.accounts.raised_token_ata.amount >= lbp_data.raised_token_min_cap
+        || lbp_data.raised_token_deposited >= lbp_data.raised_token_min_cap
     {
         return err!(ErrorCode::InvalidPhaseChange);
     }
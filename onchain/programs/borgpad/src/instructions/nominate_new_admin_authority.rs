@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::ErrorCode;
+use crate::state::admin_proposal::*;
 use crate::state::config::*;
+use crate::utils::args_hash;
 
 #[derive(Accounts)]
 pub struct NominateNewAdminAuthority<'info> {
@@ -14,12 +16,29 @@ pub struct NominateNewAdminAuthority<'info> {
 
     #[account(
         mut,
-        constraint = config.admin_authority == admin_authority.key() @ ErrorCode::NotAdminAuthority
+        constraint = config.is_admin(admin_authority.key()) @ ErrorCode::NotAdminAuthority
     )]
     pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
 }
 
 pub fn handler(ctx: Context<NominateNewAdminAuthority>, new_admin_authority: Pubkey) -> Result<()> {
+    ctx.accounts.config.authorize_admin_action(
+        ctx.accounts.admin_proposal.as_deref_mut(),
+        AdminAction::NominateNewAdminAuthority,
+        Pubkey::default(),
+        args_hash(&new_admin_authority)?,
+    )?;
+
     let config: &mut Account<Config> = &mut ctx.accounts.config;
     config.pending_admin_authority = Some(new_admin_authority);
 
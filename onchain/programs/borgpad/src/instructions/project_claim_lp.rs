@@ -0,0 +1,110 @@
+use crate::errors::ErrorCode;
+use crate::state::config::*;
+use crate::state::lbp::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct ProjectClaimLp<'info> {
+    #[account(
+        mut,
+        constraint = lbp.project == project.key() @ ErrorCode::NotProject
+    )]
+    pub project: Signer<'info>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    /// CHECK: dataless pda owning the token accounts of the lbp
+    #[account(
+        seeds = [
+            b"vault_authority".as_ref(),
+            lbp.key().as_ref()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = lbp.lp_mint == lp_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The lp tokens are not always held in an ata, e.g. the position nft of Meteora
+    #[account(
+        mut,
+        token::mint = lp_mint,
+        token::authority = vault_authority,
+        token::token_program = token_program,
+    )]
+    pub lbp_lp_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = project,
+        associated_token::mint = lp_mint,
+        associated_token::authority = project,
+        associated_token::token_program = token_program,
+    )]
+    pub project_lp_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ProjectClaimLp>) -> Result<()> {
+    ctx.accounts.config.check_not_paused(&ctx.accounts.lbp, false)?;
+
+    let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
+
+    if lbp_data.pool_status != PoolStatus::PoolCreated {
+        return err!(ErrorCode::PoolNotCreated)
+    }
+
+    let now = Clock::get()?.unix_timestamp as u64;
+    // The lp tokens withdrawn by the harvests can bring the unlocked amount below the claimed one
+    let amount = lbp_data.lp_token_unlocked_amount(now).saturating_sub(lbp_data.lp_token_claimed);
+
+    if amount == 0 {
+        return err!(ErrorCode::NothingToClaim)
+    }
+
+    // Transfer the unlocked lp tokens from lbp to project
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.lbp_lp_token_account.to_account_info(),
+                to: ctx.accounts.project_lp_token_ata.to_account_info(),
+                mint: ctx.accounts.lp_mint.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            &[&[b"vault_authority", lbp_data.key().as_ref(), &[ctx.bumps.vault_authority]]],
+        ),
+        amount,
+        ctx.accounts.lp_mint.decimals,
+    )?;
+
+    lbp_data.lp_token_claimed += amount;
+
+    Ok(())
+}
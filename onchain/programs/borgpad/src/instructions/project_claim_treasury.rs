@@ -0,0 +1,117 @@
+use crate::errors::ErrorCode;
+use crate::state::config::*;
+use crate::state::lbp::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct ProjectClaimTreasury<'info> {
+    #[account(
+        mut,
+        constraint = lbp.project == project.key() @ ErrorCode::NotProject
+    )]
+    pub project: Signer<'info>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    /// CHECK: dataless pda owning the token accounts of the lbp
+    #[account(
+        seeds = [
+            b"vault_authority".as_ref(),
+            lbp.key().as_ref()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = raised_token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub lbp_raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = project,
+        associated_token::mint = raised_token_mint,
+        associated_token::authority = project,
+        associated_token::token_program = token_program,
+    )]
+    pub project_raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = lbp.raised_token_mint == raised_token_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub raised_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ProjectClaimTreasury>) -> Result<()> {
+    ctx.accounts.config.check_not_paused(&ctx.accounts.lbp, false)?;
+
+    let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
+
+    if lbp_data.phase != Phase::Vesting {
+        return err!(ErrorCode::UnauthorisedOperationInCurrentPhase)
+    }
+
+    if lbp_data.pool_status != PoolStatus::PoolCreated {
+        return err!(ErrorCode::PoolNotCreated)
+    }
+
+    // The milestones are released by the proposals approved by the position holders
+    if !matches!(lbp_data.treasury_release, TreasuryRelease::Vesting { .. }) {
+        return err!(ErrorCode::InvalidTreasuryRelease)
+    }
+
+    let now = Clock::get()?.unix_timestamp as u64;
+    let amount = lbp_data.treasury_unlocked_amount(now) - lbp_data.raised_token_treasury_claimed;
+
+    if amount == 0 {
+        return err!(ErrorCode::NothingToClaim)
+    }
+
+    // Transfer the unlocked treasury from lbp to project
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.lbp_raised_token_ata.to_account_info(),
+                to: ctx.accounts.project_raised_token_ata.to_account_info(),
+                mint: ctx.accounts.raised_token_mint.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            &[&[b"vault_authority", lbp_data.key().as_ref(), &[ctx.bumps.vault_authority]]],
+        ),
+        amount,
+        ctx.accounts.raised_token_mint.decimals,
+    )?;
+
+    lbp_data.raised_token_treasury_claimed += amount;
+
+    Ok(())
+}
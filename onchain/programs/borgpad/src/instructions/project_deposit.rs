@@ -22,6 +22,7 @@ pub struct ProjectDeposit<'info> {
     pub config: Box<Account<'info, Config>>,
 
     #[account(
+        mut,
         seeds = [
             b"lbp".as_ref(),
             & lbp.uid.to_le_bytes()
@@ -30,6 +31,16 @@ pub struct ProjectDeposit<'info> {
     )]
     pub lbp: Box<Account<'info, Lbp>>,
 
+    /// CHECK: dataless pda owning the token accounts of the lbp
+    #[account(
+        seeds = [
+            b"vault_authority".as_ref(),
+            lbp.key().as_ref()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
     #[account(
         constraint = lbp.launched_token_mint == launched_token_mint.key() @ ErrorCode::InvalidMint
     )]
@@ -46,7 +57,7 @@ pub struct ProjectDeposit<'info> {
     #[account(
         mut,
         associated_token::mint = launched_token_mint,
-        associated_token::authority = lbp,
+        associated_token::authority = vault_authority,
         associated_token::token_program = token_program,
     )]
     pub lbp_launched_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
@@ -61,6 +72,8 @@ pub struct ProjectDeposit<'info> {
 }
 
 pub fn handler(ctx: Context<ProjectDeposit>, amount: u64) -> Result<()> {
+    ctx.accounts.config.check_not_paused(&ctx.accounts.lbp, false)?;
+
     let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
 
     if lbp_data.phase != Phase::FundCollection {
@@ -71,7 +84,7 @@ pub fn handler(ctx: Context<ProjectDeposit>, amount: u64) -> Result<()> {
         return err!(ErrorCode::InvalidAmount)
     }
 
-    if ctx.accounts.lbp_launched_token_ata.amount == lbp_data.launched_token_cap {
+    if lbp_data.launched_token_deposited > 0 {
         return err!(ErrorCode::MaxCapReached);
     }
 
@@ -90,5 +103,7 @@ pub fn handler(ctx: Context<ProjectDeposit>, amount: u64) -> Result<()> {
         ctx.accounts.launched_token_mint.decimals,
     )?;
 
+    ctx.accounts.lbp.launched_token_deposited = amount;
+
     Ok(())
 }
@@ -22,6 +22,7 @@ pub struct ProjectRefund<'info> {
     pub config: Account<'info, Config>,
 
     #[account(
+        mut,
         seeds = [
             b"lbp".as_ref(),
             & lbp.uid.to_le_bytes()
@@ -30,6 +31,16 @@ pub struct ProjectRefund<'info> {
     )]
     pub lbp: Box<Account<'info, Lbp>>,
 
+    /// CHECK: dataless pda owning the token accounts of the lbp
+    #[account(
+        seeds = [
+            b"vault_authority".as_ref(),
+            lbp.key().as_ref()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
     #[account(
         mut,
         associated_token::mint = launched_token_mint,
@@ -41,7 +52,7 @@ pub struct ProjectRefund<'info> {
     #[account(
         mut,
         associated_token::mint = launched_token_mint,
-        associated_token::authority = lbp,
+        associated_token::authority = vault_authority,
         associated_token::token_program = token_program,
     )]
     pub lbp_launched_token_ata: InterfaceAccount<'info, TokenAccount>,
@@ -61,11 +72,15 @@ pub struct ProjectRefund<'info> {
 }
 
 pub fn handler(ctx: Context<ProjectRefund>) -> Result<()> {
+    ctx.accounts.config.check_not_paused(&ctx.accounts.lbp, true)?;
+
     if ctx.accounts.lbp.phase != Phase::Refund {
         return err!(ErrorCode::UnauthorisedOperationInCurrentPhase)
     }
 
-    if ctx.accounts.project_launched_token_ata.amount == 0 {
+    // The recorded deposit is refunded rather than the vault balance, to which anyone can transfer
+    let amount = ctx.accounts.lbp.launched_token_deposited;
+    if amount == 0 {
         return err!(ErrorCode::AlreadyRefunded)
     }
 
@@ -77,13 +92,15 @@ pub fn handler(ctx: Context<ProjectRefund>) -> Result<()> {
                 from: ctx.accounts.lbp_launched_token_ata.to_account_info(),
                 to: ctx.accounts.project_launched_token_ata.to_account_info(),
                 mint: ctx.accounts.launched_token_mint.to_account_info(),
-                authority: ctx.accounts.lbp.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
             },
-            &[&[b"lbp", &ctx.accounts.lbp.uid.to_le_bytes(), &[ctx.bumps.lbp]]],
+            &[&[b"vault_authority", ctx.accounts.lbp.key().as_ref(), &[ctx.bumps.vault_authority]]],
         ),
-        ctx.accounts.lbp.launched_token_cap,
+        amount,
         ctx.accounts.launched_token_mint.decimals,
     )?;
 
+    ctx.accounts.lbp.launched_token_deposited = 0;
+
     Ok(())
 }
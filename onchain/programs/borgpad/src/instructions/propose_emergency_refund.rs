@@ -0,0 +1,104 @@
+use crate::errors::ErrorCode;
+use crate::state::config::*;
+use crate::state::lbp::*;
+use crate::state::position::*;
+use crate::state::proposal::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct ProposeEmergencyRefund<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    #[account(
+        init,
+        payer = user,
+        space = Proposal::LEN,
+        seeds = [
+            b"proposal".as_ref(),
+            lbp.key().as_ref(),
+            & lbp.proposal_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        seeds = [
+            b"position".as_ref(),
+            lbp.key().as_ref(),
+            position_mint.key().as_ref()
+        ],
+        bump,
+        constraint = position.lbp == lbp.key() @ ErrorCode::InvalidPosition
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    #[account(
+        constraint = position.mint == position_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub position_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        associated_token::mint = position_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_position_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ProposeEmergencyRefund>) -> Result<()> {
+    let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
+
+    if lbp_data.phase != Phase::Vesting {
+        return err!(ErrorCode::UnauthorisedOperationInCurrentPhase)
+    }
+
+    if ctx.accounts.user_position_ata.amount != 1 {
+        return err!(ErrorCode::DoesNotHoldPosition)
+    }
+
+    // The liquidity is only provided to the pool once created, it cannot be refunded
+    if lbp_data.pool_status != PoolStatus::PoolCreated {
+        return err!(ErrorCode::PoolNotCreated)
+    }
+
+    let now = Clock::get()?.unix_timestamp as u64;
+
+    // The amount is indicative, the treasury left when the proposal passes is refunded
+    ctx.accounts.proposal.initialize(
+        lbp_data.key(),
+        lbp_data.proposal_count,
+        ProposalKind::EmergencyRefund,
+        lbp_data.raised_token_treasury - lbp_data.raised_token_treasury_claimed,
+        now + lbp_data.governance.voting_duration,
+        ctx.bumps.proposal,
+    );
+
+    // The emergency refunds do not take the slot of the tranche requests, so they cannot hold them back
+    // Concurrent ones all lead to the same refund, the first one approved moving the lbp out of the vesting
+    lbp_data.proposal_count += 1;
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use crate::errors::ErrorCode;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+use crate::state::lbp::*;
+use crate::utils::args_hash;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+/// Returns to the payer of the lbp the lamports left to the vault authority once it no longer
+/// has a pool to create
+#[derive(Accounts)]
+pub struct ReclaimPoolFunding<'info> {
+    /// The admin or the payer of the lbp
+    #[account(
+        constraint = config.is_admin(authority.key()) || lbp.payer == authority.key() @ ErrorCode::NotAdminAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// CHECK: receives the lamports of the vault authority, checked against the lbp
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    #[account(
+        has_one = payer @ ErrorCode::InvalidPayer,
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    /// CHECK: dataless pda owning the token accounts of the lbp
+    #[account(
+        mut,
+        seeds = [
+            b"vault_authority".as_ref(),
+            lbp.key().as_ref()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ReclaimPoolFunding>) -> Result<()> {
+    // The payer of the lbp recovers its own funding, anyone else goes through the checks of the admin actions
+    if ctx.accounts.lbp.payer != ctx.accounts.authority.key() {
+        ctx.accounts.config.authorize_admin_action(
+            ctx.accounts.admin_proposal.as_deref_mut(),
+            AdminAction::ReclaimPoolFunding,
+            ctx.accounts.lbp.key(),
+            args_hash(&())?,
+        )?;
+    }
+
+    let lbp_data: &Account<Lbp> = &ctx.accounts.lbp;
+
+    // The funding pays the pool creation, so it is kept until the pool is created or the sale refunded
+    if lbp_data.pool_status != PoolStatus::PoolCreated && lbp_data.phase != Phase::Refund {
+        return err!(ErrorCode::UnauthorisedOperationInCurrentPhase)
+    }
+
+    let lamports = ctx.accounts.vault_authority.lamports();
+    if lamports == 0 {
+        return err!(ErrorCode::NothingToClaim)
+    }
+
+    let lbp_key = lbp_data.key();
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault_authority.to_account_info(),
+                to: ctx.accounts.payer.to_account_info(),
+            },
+            &[&[b"vault_authority", lbp_key.as_ref(), &[ctx.bumps.vault_authority]]],
+        ),
+        lamports,
+    )?;
+
+    Ok(())
+}
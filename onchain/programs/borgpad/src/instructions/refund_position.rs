@@ -0,0 +1,134 @@
+use crate::errors::ErrorCode;
+use crate::state::config::*;
+use crate::state::lbp::*;
+use crate::state::position::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct RefundPosition<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    /// CHECK: dataless pda owning the token accounts of the lbp
+    #[account(
+        seeds = [
+            b"vault_authority".as_ref(),
+            lbp.key().as_ref()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = position_payer,
+        seeds = [
+            b"position".as_ref(),
+            lbp.key().as_ref(),
+            position_mint.key().as_ref()
+        ],
+        bump,
+        constraint = position.lbp == lbp.key() @ ErrorCode::InvalidPosition
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    /// CHECK: receives the rent of the position, checked against the position
+    #[account(
+        mut,
+        constraint = position.payer == position_payer.key() @ ErrorCode::InvalidPayer
+    )]
+    pub position_payer: UncheckedAccount<'info>,
+
+    /// CHECK: the holder of the position, checked through its position token account
+    pub holder: UncheckedAccount<'info>,
+
+    #[account(
+        associated_token::mint = position_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+    )]
+    pub holder_position_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = raised_token_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+    )]
+    pub holder_raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = raised_token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub lbp_raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = position.mint == position_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub position_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = lbp.raised_token_mint == raised_token_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub raised_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RefundPosition>) -> Result<()> {
+    ctx.accounts.config.check_not_paused(&ctx.accounts.lbp, true)?;
+
+    // The refund can only go to the holder of the position, so it does not need its signature
+    if ctx.accounts.holder_position_ata.amount != 1 {
+        return err!(ErrorCode::DoesNotHoldPosition)
+    }
+
+    // The position is closed along with the refund, the nft left to the holder is worthless
+    let amount = ctx.accounts.lbp.settle_refund(&ctx.accounts.position)?;
+
+    // Transfer funds from lbp to holder
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.lbp_raised_token_ata.to_account_info(),
+                to: ctx.accounts.holder_raised_token_ata.to_account_info(),
+                mint: ctx.accounts.raised_token_mint.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            &[&[b"vault_authority", ctx.accounts.lbp.key().as_ref(), &[ctx.bumps.vault_authority]]],
+        ),
+        amount,
+        ctx.accounts.raised_token_mint.decimals,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use crate::errors::ErrorCode;
+use crate::state::config::*;
+use crate::state::lbp::*;
+use crate::state::proposal::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RequestTranche<'info> {
+    #[account(
+        mut,
+        constraint = lbp.project == project.key() @ ErrorCode::NotProject
+    )]
+    pub project: Signer<'info>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    #[account(
+        init,
+        payer = project,
+        space = Proposal::LEN,
+        seeds = [
+            b"proposal".as_ref(),
+            lbp.key().as_ref(),
+            & lbp.proposal_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RequestTranche>) -> Result<()> {
+    let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
+
+    if lbp_data.phase != Phase::Vesting {
+        return err!(ErrorCode::UnauthorisedOperationInCurrentPhase)
+    }
+
+    if lbp_data.pool_status != PoolStatus::PoolCreated {
+        return err!(ErrorCode::PoolNotCreated)
+    }
+
+    let TreasuryRelease::Milestones { tranche_count } = lbp_data.treasury_release else {
+        return err!(ErrorCode::InvalidTreasuryRelease)
+    };
+
+    if lbp_data.open_proposal {
+        return err!(ErrorCode::ProposalAlreadyOpen)
+    }
+
+    if lbp_data.treasury_tranches_released >= tranche_count {
+        return err!(ErrorCode::AllTranchesReleased)
+    }
+
+    let now = Clock::get()?.unix_timestamp as u64;
+
+    ctx.accounts.proposal.initialize(
+        lbp_data.key(),
+        lbp_data.proposal_count,
+        ProposalKind::ReleaseTranche,
+        lbp_data.next_treasury_tranche_amount(tranche_count),
+        now + lbp_data.governance.voting_duration,
+        ctx.bumps.proposal,
+    );
+
+    lbp_data.proposal_count += 1;
+    lbp_data.open_proposal = true;
+
+    Ok(())
+}
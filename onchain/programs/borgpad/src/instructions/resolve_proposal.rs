@@ -0,0 +1,151 @@
+use crate::errors::ErrorCode;
+use crate::state::config::*;
+use crate::state::lbp::*;
+use crate::state::proposal::*;
+use crate::utils::bps_of;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct ResolveProposal<'info> {
+    /// Anyone can resolve a proposal, the payer only funds the creation of the missing token accounts
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    /// CHECK: dataless pda owning the token accounts of the lbp
+    #[account(
+        seeds = [
+            b"vault_authority".as_ref(),
+            lbp.key().as_ref()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"proposal".as_ref(),
+            lbp.key().as_ref(),
+            & proposal.index.to_le_bytes()
+        ],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        mut,
+        associated_token::mint = raised_token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub lbp_raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: only used as the authority of the project ata
+    #[account(
+        constraint = lbp.project == project.key() @ ErrorCode::NotProject
+    )]
+    pub project: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = raised_token_mint,
+        associated_token::authority = project,
+        associated_token::token_program = token_program,
+    )]
+    pub project_raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = lbp.raised_token_mint == raised_token_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub raised_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ResolveProposal>) -> Result<()> {
+    ctx.accounts.config.check_not_paused(&ctx.accounts.lbp, false)?;
+
+    let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
+    let proposal_data: &mut Account<Proposal> = &mut ctx.accounts.proposal;
+
+    if proposal_data.status != ProposalStatus::Voting {
+        return err!(ErrorCode::ProposalResolved)
+    }
+
+    if (Clock::get()?.unix_timestamp as u64) < proposal_data.voting_end_time {
+        return err!(ErrorCode::VotingInProgress)
+    }
+
+    // The votes are valid if the quorum is reached, and the proposal passes if enough of them approve it
+    let votes = proposal_data.votes_for + proposal_data.votes_against;
+    let approved = votes >= bps_of(lbp_data.raised_token_cap, lbp_data.governance.quorum_bps)
+        && proposal_data.votes_for > 0
+        && proposal_data.votes_for >= bps_of(votes, lbp_data.governance.threshold_bps);
+
+    if proposal_data.kind == ProposalKind::ReleaseTranche {
+        lbp_data.open_proposal = false;
+    }
+
+    // A proposal resolved after an emergency refund was approved no longer applies
+    if !approved || lbp_data.phase != Phase::Vesting {
+        proposal_data.status = ProposalStatus::Rejected;
+        return Ok(());
+    }
+
+    proposal_data.status = ProposalStatus::Approved;
+
+    match proposal_data.kind {
+        ProposalKind::ReleaseTranche => {
+            // Transfer the tranche from lbp to project
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.lbp_raised_token_ata.to_account_info(),
+                        to: ctx.accounts.project_raised_token_ata.to_account_info(),
+                        mint: ctx.accounts.raised_token_mint.to_account_info(),
+                        authority: ctx.accounts.vault_authority.to_account_info(),
+                    },
+                    &[&[b"vault_authority", lbp_data.key().as_ref(), &[ctx.bumps.vault_authority]]],
+                ),
+                proposal_data.amount,
+                ctx.accounts.raised_token_mint.decimals,
+            )?;
+
+            lbp_data.raised_token_treasury_claimed += proposal_data.amount;
+            lbp_data.treasury_tranches_released += 1;
+        }
+        ProposalKind::EmergencyRefund => {
+            // The unreleased treasury stays in the lbp until refunded to the positions
+            lbp_data.phase = Phase::EmergencyRefund;
+            lbp_data.emergency_refund_amount = lbp_data.raised_token_treasury - lbp_data.raised_token_treasury_claimed;
+        }
+    }
+
+    Ok(())
+}
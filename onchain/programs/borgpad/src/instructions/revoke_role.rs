@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+use crate::state::role::*;
+use crate::utils::args_hash;
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(
+        mut,
+        seeds = [b"config".as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = config.is_admin(admin_authority.key()) @ ErrorCode::NotAdminAuthority
+    )]
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
+}
+
+pub fn handler(ctx: Context<RevokeRole>, role: Role) -> Result<()> {
+    ctx.accounts.config.authorize_admin_action(
+        ctx.accounts.admin_proposal.as_deref_mut(),
+        AdminAction::RevokeRole,
+        Pubkey::default(),
+        args_hash(&role)?,
+    )?;
+
+    // Both the holder and the pending holder lose the role
+    let config: &mut Account<Config> = &mut ctx.accounts.config;
+    if !config.roles.iter().any(|grant| grant.role == role) {
+        return err!(ErrorCode::RoleNotGranted)
+    }
+
+    config.roles.retain(|grant| grant.role != role);
+
+    Ok(())
+}
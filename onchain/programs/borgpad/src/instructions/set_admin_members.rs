@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_ADMIN_MEMBERS;
+use crate::errors::ErrorCode;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+use crate::utils::args_hash;
+
+#[derive(Accounts)]
+pub struct SetAdminMembers<'info> {
+    #[account(
+        mut,
+        seeds = [b"config".as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = config.is_admin(admin_authority.key()) @ ErrorCode::NotAdminAuthority
+    )]
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
+}
+
+pub fn handler(ctx: Context<SetAdminMembers>, admin_members: Vec<Pubkey>, admin_threshold: u8) -> Result<()> {
+    ctx.accounts.config.authorize_admin_action(
+        ctx.accounts.admin_proposal.as_deref_mut(),
+        AdminAction::SetAdminMembers,
+        Pubkey::default(),
+        args_hash(&(admin_members.clone(), admin_threshold))?,
+    )?;
+
+    // A threshold of 0 hands the sensitive actions back to the admin authority alone
+    let distinct = admin_members.iter()
+        .enumerate()
+        .all(|(i, member)| !admin_members[..i].contains(member));
+
+    if admin_members.len() > MAX_ADMIN_MEMBERS as usize
+        || !distinct
+        || admin_threshold as usize > admin_members.len()
+        || (admin_threshold == 0) != admin_members.is_empty()
+    {
+        return err!(ErrorCode::InvalidAdminMembers);
+    }
+
+    let config: &mut Account<Config> = &mut ctx.accounts.config;
+    config.admin_members = admin_members;
+    config.admin_threshold = admin_threshold;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+use crate::utils::args_hash;
+
+#[derive(Accounts)]
+pub struct SetAdminTimelockDelay<'info> {
+    #[account(
+        mut,
+        seeds = [b"config".as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = config.is_admin(admin_authority.key()) @ ErrorCode::NotAdminAuthority
+    )]
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
+}
+
+pub fn handler(ctx: Context<SetAdminTimelockDelay>, admin_timelock_delay: u64) -> Result<()> {
+    ctx.accounts.config.authorize_admin_action(
+        ctx.accounts.admin_proposal.as_deref_mut(),
+        AdminAction::SetAdminTimelockDelay,
+        Pubkey::default(),
+        args_hash(&admin_timelock_delay)?,
+    )?;
+
+    let config: &mut Account<Config> = &mut ctx.accounts.config;
+    config.admin_timelock_delay = admin_timelock_delay;
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+use crate::utils::args_hash;
+
+#[derive(Accounts)]
+pub struct SetDexPrograms<'info> {
+    #[account(
+        mut,
+        seeds = [b"config".as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = config.is_admin(admin_authority.key()) @ ErrorCode::NotAdminAuthority
+    )]
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
+}
+
+pub fn handler(
+    ctx: Context<SetDexPrograms>,
+    raydium_cp_swap_program: Pubkey,
+    orca_whirlpool_program: Pubkey,
+    meteora_damm_program: Pubkey,
+) -> Result<()> {
+    ctx.accounts.config.authorize_admin_action(
+        ctx.accounts.admin_proposal.as_deref_mut(),
+        AdminAction::SetDexPrograms,
+        Pubkey::default(),
+        args_hash(&(raydium_cp_swap_program, orca_whirlpool_program, meteora_damm_program))?,
+    )?;
+
+    let config: &mut Account<Config> = &mut ctx.accounts.config;
+    config.raydium_cp_swap_program = raydium_cp_swap_program;
+    config.orca_whirlpool_program = orca_whirlpool_program;
+    config.meteora_damm_program = meteora_damm_program;
+
+    Ok(())
+}
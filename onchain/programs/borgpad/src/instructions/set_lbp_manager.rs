@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+use crate::state::lbp::*;
+use crate::state::role::Role;
+use crate::utils::args_hash;
+
+#[derive(Accounts)]
+pub struct SetLbpManager<'info> {
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    /// The admin, or the holder of the sale operator role
+    #[account(
+        constraint = config.is_admin_or(Role::SaleOperator, admin_authority.key()) @ ErrorCode::NotAdminAuthority
+    )]
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
+}
+
+pub fn handler(ctx: Context<SetLbpManager>, manager: Option<Pubkey>) -> Result<()> {
+    // The manager cannot appoint its successor
+    ctx.accounts.config.authorize_role_or_admin_action(
+        Role::SaleOperator,
+        ctx.accounts.admin_authority.key(),
+        ctx.accounts.admin_proposal.as_deref_mut(),
+        AdminAction::SetLbpManager,
+        ctx.accounts.lbp.key(),
+        args_hash(&manager)?,
+    )?;
+
+    let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
+    lbp_data.manager = manager;
+
+    Ok(())
+}
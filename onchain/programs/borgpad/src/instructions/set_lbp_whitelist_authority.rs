@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+use crate::state::lbp::*;
+use crate::utils::args_hash;
+
+#[derive(Accounts)]
+pub struct SetLbpWhitelistAuthority<'info> {
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    /// The admin, the holder of the sale operator role or the manager of the lbp
+    #[account(
+        constraint = config.is_lbp_admin(&lbp, admin_authority.key()) @ ErrorCode::NotAdminAuthority
+    )]
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
+}
+
+pub fn handler(ctx: Context<SetLbpWhitelistAuthority>, whitelist_authority: Option<Pubkey>) -> Result<()> {
+    ctx.accounts.config.authorize_lbp_action(
+        &ctx.accounts.lbp,
+        ctx.accounts.admin_authority.key(),
+        ctx.accounts.admin_proposal.as_deref_mut(),
+        AdminAction::SetLbpWhitelistAuthority,
+        args_hash(&whitelist_authority)?,
+    )?;
+
+    // Unset, the deposits are whitelisted by the authority of the config
+    let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
+    lbp_data.whitelist_authority = whitelist_authority;
+
+    Ok(())
+}
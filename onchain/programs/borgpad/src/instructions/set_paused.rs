@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+use crate::state::role::Role;
+use crate::utils::args_hash;
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(
+        mut,
+        seeds = [b"config".as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        constraint = config.is_pauser(pauser_authority.key()) @ ErrorCode::NotPauserAuthority
+    )]
+    pub pauser_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
+}
+
+pub fn handler(ctx: Context<SetPaused>, paused: bool, refunds_exempt_from_pause: bool) -> Result<()> {
+    ctx.accounts.config.authorize_role_or_admin_action(
+        Role::Pauser,
+        ctx.accounts.pauser_authority.key(),
+        ctx.accounts.admin_proposal.as_deref_mut(),
+        AdminAction::SetPaused,
+        Pubkey::default(),
+        args_hash(&(paused, refunds_exempt_from_pause))?,
+    )?;
+
+    let config: &mut Account<Config> = &mut ctx.accounts.config;
+    config.paused = paused;
+    config.refunds_exempt_from_pause = refunds_exempt_from_pause;
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_REFERRAL_FEE_BPS;
+use crate::errors::ErrorCode;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+use crate::state::role::Role;
+use crate::utils::args_hash;
+
+#[derive(Accounts)]
+pub struct SetReferralFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"config".as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The admin, or the holder of the fee manager role
+    #[account(
+        mut,
+        constraint = config.is_admin_or(Role::FeeManager, admin_authority.key()) @ ErrorCode::NotAdminAuthority
+    )]
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
+}
+
+pub fn handler(
+    ctx: Context<SetReferralFees>,
+    referral_raised_token_fee_bps: u16,
+    referral_launched_token_fee_bps: u16,
+) -> Result<()> {
+    ctx.accounts.config.authorize_role_or_admin_action(
+        Role::FeeManager,
+        ctx.accounts.admin_authority.key(),
+        ctx.accounts.admin_proposal.as_deref_mut(),
+        AdminAction::SetReferralFees,
+        Pubkey::default(),
+        args_hash(&(referral_raised_token_fee_bps, referral_launched_token_fee_bps))?,
+    )?;
+
+    if referral_raised_token_fee_bps > MAX_REFERRAL_FEE_BPS
+        || referral_launched_token_fee_bps > MAX_REFERRAL_FEE_BPS
+    {
+        return err!(ErrorCode::FeeTooHigh);
+    }
+
+    let config: &mut Account<Config> = &mut ctx.accounts.config;
+    config.referral_raised_token_fee_bps = referral_raised_token_fee_bps;
+    config.referral_launched_token_fee_bps = referral_launched_token_fee_bps;
+
+    Ok(())
+}
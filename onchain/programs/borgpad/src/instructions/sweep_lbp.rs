@@ -0,0 +1,214 @@
+use crate::errors::ErrorCode;
+use crate::events::LbpSwept;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+use crate::state::lbp::*;
+use crate::state::role::Role;
+use crate::utils::args_hash;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+/// Where the swept tokens are sent
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Eq, PartialEq)]
+pub enum SweepDestination {
+    Project,
+    FeeTreasury,
+}
+
+#[derive(Accounts)]
+pub struct SweepLbp<'info> {
+    /// The admin authority, or the holder of the treasury role
+    #[account(
+        mut,
+        constraint = config.is_admin_or(Role::Treasury, authority.key()) @ ErrorCode::NotAdminAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    /// CHECK: dataless pda owning the token accounts of the lbp
+    #[account(
+        seeds = [
+            b"vault_authority".as_ref(),
+            lbp.key().as_ref()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// CHECK: the owner of the token accounts receiving the sweep, checked against the destination
+    pub destination: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = launched_token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub lbp_launched_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = raised_token_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub lbp_raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = launched_token_mint,
+        associated_token::authority = destination,
+        associated_token::token_program = token_program,
+    )]
+    pub destination_launched_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = raised_token_mint,
+        associated_token::authority = destination,
+        associated_token::token_program = token_program,
+    )]
+    pub destination_raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = lbp.launched_token_mint == launched_token_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub launched_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = lbp.raised_token_mint == raised_token_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub raised_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SweepLbp>, destination: SweepDestination) -> Result<()> {
+    ctx.accounts.config.check_not_paused(&ctx.accounts.lbp, false)?;
+
+    ctx.accounts.config.authorize_role_or_admin_action(
+        Role::Treasury,
+        ctx.accounts.authority.key(),
+        ctx.accounts.admin_proposal.as_deref_mut(),
+        AdminAction::SweepLbp,
+        ctx.accounts.lbp.key(),
+        args_hash(&destination)?,
+    )?;
+
+    let lbp_data: &Account<Lbp> = &ctx.accounts.lbp;
+
+    let destination_key = match destination {
+        SweepDestination::Project => lbp_data.project,
+        SweepDestination::FeeTreasury => ctx.accounts.config.fee_treasury,
+    };
+
+    if ctx.accounts.destination.key() != destination_key {
+        return err!(ErrorCode::InvalidDestination)
+    }
+
+    if lbp_data.phase == Phase::FundCollection {
+        return err!(ErrorCode::UnauthorisedOperationInCurrentPhase)
+    }
+
+    // The vaults still hold the liquidity of the pool until it is created
+    if lbp_data.phase == Phase::Vesting && lbp_data.pool_status != PoolStatus::PoolCreated {
+        return err!(ErrorCode::PoolNotCreated)
+    }
+
+    // The leftovers are only swept once the claim window is over
+    let now = Clock::get()?.unix_timestamp as u64;
+    if now < lbp_data.claim_deadline {
+        return err!(ErrorCode::ClaimWindowOpen)
+    }
+
+    // Whatever the lbp still owes is left in the vaults, anything else held by the lbp was either left
+    // over by the rounding of the shares or sent directly to it
+    let (launched_token_reserved, raised_token_reserved) = lbp_data.reserved_amounts();
+    let launched_token_amount = ctx.accounts.lbp_launched_token_ata.amount
+        .saturating_sub(launched_token_reserved);
+    let raised_token_amount = ctx.accounts.lbp_raised_token_ata.amount
+        .saturating_sub(raised_token_reserved);
+
+    if launched_token_amount == 0 && raised_token_amount == 0 {
+        return err!(ErrorCode::NothingToClaim)
+    }
+
+    let lbp_key = lbp_data.key();
+    let signer_seeds: &[&[u8]] = &[b"vault_authority", lbp_key.as_ref(), &[ctx.bumps.vault_authority]];
+
+    if launched_token_amount > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.lbp_launched_token_ata.to_account_info(),
+                    to: ctx.accounts.destination_launched_token_ata.to_account_info(),
+                    mint: ctx.accounts.launched_token_mint.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                &[signer_seeds],
+            ),
+            launched_token_amount,
+            ctx.accounts.launched_token_mint.decimals,
+        )?;
+    }
+
+    if raised_token_amount > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.lbp_raised_token_ata.to_account_info(),
+                    to: ctx.accounts.destination_raised_token_ata.to_account_info(),
+                    mint: ctx.accounts.raised_token_mint.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                &[signer_seeds],
+            ),
+            raised_token_amount,
+            ctx.accounts.raised_token_mint.decimals,
+        )?;
+    }
+
+    emit!(LbpSwept {
+        lbp: lbp_key,
+        destination: destination_key,
+        launched_token_amount,
+        raised_token_amount,
+    });
+
+    Ok(())
+}
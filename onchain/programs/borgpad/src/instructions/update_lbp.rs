@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::LbpUpdated;
+use crate::state::admin_proposal::*;
+use crate::state::config::*;
+use crate::state::lbp::*;
+use crate::utils::args_hash;
+
+#[derive(Accounts)]
+pub struct UpdateLbp<'info> {
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    /// The admin, the holder of the sale operator role or the manager of the lbp
+    #[account(
+        constraint = config.is_lbp_admin(&lbp, admin_authority.key()) @ ErrorCode::NotAdminAuthority
+    )]
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"admin_proposal".as_ref(),
+            &admin_proposal.index.to_le_bytes()
+        ],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Option<Box<Account<'info, AdminProposal>>>,
+}
+
+pub fn handler(ctx: Context<UpdateLbp>, parameters: LbpParameters) -> Result<()> {
+    ctx.accounts.config.authorize_lbp_action(
+        &ctx.accounts.lbp,
+        ctx.accounts.admin_authority.key(),
+        ctx.accounts.admin_proposal.as_deref_mut(),
+        AdminAction::UpdateLbp,
+        args_hash(&parameters)?,
+    )?;
+
+    let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
+
+    if lbp_data.phase != Phase::FundCollection {
+        return err!(ErrorCode::UnauthorisedOperationInCurrentPhase);
+    }
+
+    // The deposits of the project and of the users are made against the current caps
+    // The recorded amounts are checked rather than the vaults, to which anyone can transfer
+    if lbp_data.launched_token_deposited > 0 || lbp_data.raised_token_deposited > 0 {
+        return err!(ErrorCode::LbpAlreadyFunded);
+    }
+
+    parameters.validate()?;
+
+    let old = lbp_data.parameters();
+    lbp_data.update(parameters.clone());
+
+    emit!(LbpUpdated {
+        lbp: lbp_data.key(),
+        old,
+        new: parameters,
+    });
+
+    Ok(())
+}
@@ -2,6 +2,7 @@ use crate::errors::ErrorCode;
 use crate::state::config::*;
 use crate::state::lbp::*;
 use crate::state::position::*;
+use crate::state::referral::*;
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{set_authority, SetAuthority};
@@ -11,10 +12,11 @@ use anchor_spl::token_interface::{
 };
 
 #[derive(Accounts)]
+#[instruction(amount: u64, referrer: Option<Pubkey>)]
 pub struct UserDeposit<'info> {
     #[account(
         mut,
-        constraint = config.whitelist_authority == whitelist_authority.key() @ ErrorCode::NotWhitelistAuthority
+        constraint = lbp.whitelist_authority(&config) == whitelist_authority.key() @ ErrorCode::NotWhitelistAuthority
     )]
     pub whitelist_authority: Signer<'info>,
 
@@ -28,6 +30,7 @@ pub struct UserDeposit<'info> {
     pub config: Box<Account<'info, Config>>,
 
     #[account(
+        mut,
         seeds = [
             b"lbp".as_ref(),
             & lbp.uid.to_le_bytes()
@@ -36,6 +39,29 @@ pub struct UserDeposit<'info> {
     )]
     pub lbp: Box<Account<'info, Lbp>>,
 
+    /// CHECK: dataless pda owning the token accounts of the lbp
+    #[account(
+        seeds = [
+            b"vault_authority".as_ref(),
+            lbp.key().as_ref()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        space = Referral::LEN,
+        seeds = [
+            b"referral".as_ref(),
+            lbp.key().as_ref(),
+            referrer.unwrap_or_default().as_ref()
+        ],
+        bump,
+        payer = user
+    )]
+    pub referral: Option<Box<Account<'info, Referral>>>,
+
     #[account(
         init,
         payer = user,
@@ -82,7 +108,7 @@ pub struct UserDeposit<'info> {
     #[account(
         mut,
         associated_token::mint = raised_token_mint,
-        associated_token::authority = lbp,
+        associated_token::authority = vault_authority,
         associated_token::token_program = token_program,
     )]
     pub lbp_raised_token_ata: Box<InterfaceAccount<'info, TokenAccount>>,
@@ -96,14 +122,26 @@ pub struct UserDeposit<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handler(ctx: Context<UserDeposit>, amount: u64) -> Result<()> {
+pub fn handler(ctx: Context<UserDeposit>, amount: u64, referrer: Option<Pubkey>) -> Result<()> {
+    ctx.accounts.config.check_not_paused(&ctx.accounts.lbp, false)?;
+
     let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
 
     if lbp_data.phase != Phase::FundCollection {
         return err!(ErrorCode::UnauthorisedOperationInCurrentPhase)
     }
 
-    if ctx.accounts.lbp_raised_token_ata.amount + amount > lbp_data.raised_token_max_cap {
+    if referrer.is_some() != ctx.accounts.referral.is_some() {
+        return err!(ErrorCode::InvalidReferral);
+    }
+
+    // Only the depositing key is compared, a user referring itself through another wallet cannot be
+    // told apart from a genuine referrer on chain and is left to the whitelisting of the deposits
+    if referrer == Some(ctx.accounts.user.key()) {
+        return err!(ErrorCode::SelfReferral);
+    }
+
+    if lbp_data.raised_token_deposited + amount > lbp_data.raised_token_max_cap {
         return err!(ErrorCode::MaxCapReached);
     }
 
@@ -151,7 +189,27 @@ pub fn handler(ctx: Context<UserDeposit>, amount: u64) -> Result<()> {
     )?;
 
     let position_data: &mut Account<Position> = &mut ctx.accounts.position;
-    position_data.initialize(ctx.accounts.position_mint.key(), ctx.accounts.lbp.key(), amount, ctx.bumps.position);
+    position_data.initialize(
+        ctx.accounts.position_mint.key(),
+        ctx.accounts.lbp.key(),
+        ctx.accounts.user.key(),
+        amount,
+        ctx.bumps.position,
+    );
+
+    ctx.accounts.lbp.position_count += 1;
+    ctx.accounts.lbp.raised_token_deposited += amount;
+
+    if let (Some(referrer), Some(referral_data)) = (referrer, ctx.accounts.referral.as_mut()) {
+        let lbp_data: &mut Account<Lbp> = &mut ctx.accounts.lbp;
+
+        if referral_data.referrer == Pubkey::default() {
+            referral_data.initialize(lbp_data.key(), referrer, ctx.bumps.referral.unwrap());
+        }
+
+        referral_data.amount += amount;
+        lbp_data.referred_token_cap += amount;
+    }
 
     Ok(())
 }
@@ -21,6 +21,7 @@ pub struct UserRefund<'info> {
     pub config: Account<'info, Config>,
 
     #[account(
+        mut,
         seeds = [
             b"lbp".as_ref(),
             & lbp.uid.to_le_bytes()
@@ -29,9 +30,24 @@ pub struct UserRefund<'info> {
     )]
     pub lbp: Box<Account<'info, Lbp>>,
 
+    /// CHECK: dataless pda owning the token accounts of the lbp
+    #[account(
+        seeds = [
+            b"vault_authority".as_ref(),
+            lbp.key().as_ref()
+        ],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// CHECK: receives the rent of the position, checked against the position
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
     #[account(
         mut,
-        close = user,
+        close = payer,
+        has_one = payer @ ErrorCode::InvalidPayer,
         seeds = [
             b"position".as_ref(),
             lbp.key().as_ref(),
@@ -61,7 +77,7 @@ pub struct UserRefund<'info> {
     #[account(
         mut,
         associated_token::mint = raised_token_mint,
-        associated_token::authority = lbp,
+        associated_token::authority = vault_authority,
         associated_token::token_program = token_program,
     )]
     pub lbp_raised_token_ata: InterfaceAccount<'info, TokenAccount>,
@@ -87,16 +103,15 @@ pub struct UserRefund<'info> {
 }
 
 pub fn handler(ctx: Context<UserRefund>) -> Result<()> {
-    let position_data: & Account<Position> = & ctx.accounts.position;
-
-    if ctx.accounts.lbp.phase != Phase::Refund {
-        return err!(ErrorCode::UnauthorisedOperationInCurrentPhase)
-    }
+    ctx.accounts.config.check_not_paused(&ctx.accounts.lbp, true)?;
 
     if ctx.accounts.user_position_ata.amount != 1 {
         return err!(ErrorCode::DoesNotHoldPosition)
     }
 
+    // The position is closed along with the refund
+    let amount = ctx.accounts.lbp.settle_refund(&ctx.accounts.position)?;
+
     // Burn token
     burn(
         CpiContext::new(
@@ -130,11 +145,11 @@ pub fn handler(ctx: Context<UserRefund>) -> Result<()> {
                 from: ctx.accounts.lbp_raised_token_ata.to_account_info(),
                 to: ctx.accounts.user_raised_token_ata.to_account_info(),
                 mint: ctx.accounts.raised_token_mint.to_account_info(),
-                authority: ctx.accounts.lbp.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
             },
-            &[&[b"lbp", &ctx.accounts.lbp.uid.to_le_bytes(), &[ctx.bumps.lbp]]],
+            &[&[b"vault_authority", ctx.accounts.lbp.key().as_ref(), &[ctx.bumps.vault_authority]]],
         ),
-        position_data.amount,
+        amount,
         ctx.accounts.raised_token_mint.decimals,
     )?;
 
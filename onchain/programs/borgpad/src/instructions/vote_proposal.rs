@@ -0,0 +1,112 @@
+use crate::errors::ErrorCode;
+use crate::state::config::*;
+use crate::state::lbp::*;
+use crate::state::position::*;
+use crate::state::proposal::*;
+use crate::state::vote::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct VoteProposal<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"config".as_ref()],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [
+            b"lbp".as_ref(),
+            & lbp.uid.to_le_bytes()
+        ],
+        bump
+    )]
+    pub lbp: Box<Account<'info, Lbp>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"proposal".as_ref(),
+            lbp.key().as_ref(),
+            & proposal.index.to_le_bytes()
+        ],
+        bump
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        seeds = [
+            b"position".as_ref(),
+            lbp.key().as_ref(),
+            position_mint.key().as_ref()
+        ],
+        bump,
+        constraint = position.lbp == lbp.key() @ ErrorCode::InvalidPosition
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    #[account(
+        constraint = position.mint == position_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub position_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        associated_token::mint = position_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_position_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = user,
+        space = Vote::LEN,
+        seeds = [
+            b"vote".as_ref(),
+            proposal.key().as_ref(),
+            position.key().as_ref()
+        ],
+        bump
+    )]
+    pub vote: Box<Account<'info, Vote>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<VoteProposal>, approve: bool) -> Result<()> {
+    let proposal_data: &mut Account<Proposal> = &mut ctx.accounts.proposal;
+
+    if proposal_data.status != ProposalStatus::Voting
+        || Clock::get()?.unix_timestamp as u64 >= proposal_data.voting_end_time
+    {
+        return err!(ErrorCode::VotingClosed)
+    }
+
+    if ctx.accounts.user_position_ata.amount != 1 {
+        return err!(ErrorCode::DoesNotHoldPosition)
+    }
+
+    // The votes are weighted by the amount deposited by the position
+    let weight = ctx.accounts.position.amount;
+    if approve {
+        proposal_data.votes_for += weight;
+    } else {
+        proposal_data.votes_against += weight;
+    }
+
+    ctx.accounts.vote.initialize(
+        proposal_data.key(),
+        ctx.accounts.position.key(),
+        approve,
+        weight,
+        ctx.bumps.vote,
+    );
+
+    Ok(())
+}
@@ -1,18 +1,64 @@
 use crate::instructions::accept_admin_authority::*;
+use crate::instructions::accept_role::*;
+use crate::instructions::approve_admin_proposal::*;
+use crate::instructions::cancel_admin_proposal::*;
+use crate::instructions::cancel_lbp::*;
+use crate::instructions::claim_grant::*;
+use crate::instructions::claim_position_fees::*;
+use crate::instructions::claim_referral_reward::*;
+use crate::instructions::close_lbp::*;
+use crate::instructions::close_lbp_vault::*;
+use crate::instructions::close_position::*;
+use crate::instructions::create_admin_proposal::*;
+use crate::instructions::create_grant::*;
+use crate::instructions::create_pool::*;
+use crate::instructions::finalize_sale::*;
+use crate::instructions::grant_role::*;
+use crate::instructions::harvest_fees::*;
 use crate::instructions::initialize::*;
 use crate::instructions::initialize_lbp::*;
+use crate::instructions::migrate_config::*;
+use crate::instructions::migrate_lbp::*;
+use crate::instructions::migrate_lbp_vaults::*;
+use crate::instructions::migrate_position::*;
 use crate::instructions::move_to_refund_phase::*;
-use crate::instructions::move_to_vesting_phase::*;
 use crate::instructions::nominate_new_admin_authority::*;
+use crate::instructions::set_admin_members::*;
+use crate::instructions::set_admin_timelock_delay::*;
+use crate::instructions::set_dex_programs::*;
+use crate::instructions::set_lbp_manager::*;
+use crate::instructions::set_lbp_paused::*;
+use crate::instructions::set_lbp_whitelist_authority::*;
+use crate::instructions::set_paused::*;
+use crate::instructions::set_platform_fees::*;
+use crate::instructions::set_referral_fees::*;
 use crate::instructions::set_whitelist_authority::*;
+use crate::instructions::sweep_lbp::*;
+use crate::instructions::update_lbp::*;
+use crate::instructions::user_claim::*;
 use crate::instructions::user_deposit::*;
 use crate::instructions::user_refund::*;
+use crate::instructions::vote_proposal::*;
+use crate::instructions::project_claim_lp::*;
+use crate::instructions::project_claim_treasury::*;
 use crate::instructions::project_deposit::*;
 use crate::instructions::project_refund::*;
-use crate::state::lbp::LbpInitializeData;
+use crate::instructions::propose_emergency_refund::*;
+use crate::instructions::reclaim_pool_funding::*;
+use crate::instructions::refund_position::*;
+use crate::instructions::request_tranche::*;
+use crate::instructions::resolve_proposal::*;
+use crate::instructions::revoke_grant::*;
+use crate::instructions::revoke_role::*;
+use crate::state::admin_proposal::AdminAction;
+use crate::state::lbp::{CancelReason, LbpInitializeData, LbpParameters};
+use crate::state::role::Role;
+use crate::state::vesting_schedule::VestingSchedule;
 use anchor_lang::prelude::*;
 use solana_security_txt::security_txt;
 
+pub mod constants;
+pub mod dex;
 pub mod errors;
 pub mod events;
 pub mod instructions;
@@ -56,6 +102,42 @@ pub mod borgpad {
         instructions::accept_admin_authority::handler(ctx)
     }
 
+    pub fn set_admin_members(
+        ctx: Context<SetAdminMembers>,
+        admin_members: Vec<Pubkey>,
+        admin_threshold: u8,
+    ) -> Result<()> {
+        instructions::set_admin_members::handler(ctx, admin_members, admin_threshold)
+    }
+
+    pub fn create_admin_proposal(
+        ctx: Context<CreateAdminProposal>,
+        action: AdminAction,
+        target: Pubkey,
+        args_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::create_admin_proposal::handler(ctx, action, target, args_hash)
+    }
+
+    pub fn approve_admin_proposal(
+        ctx: Context<ApproveAdminProposal>,
+    ) -> Result<()> {
+        instructions::approve_admin_proposal::handler(ctx)
+    }
+
+    pub fn cancel_admin_proposal(
+        ctx: Context<CancelAdminProposal>,
+    ) -> Result<()> {
+        instructions::cancel_admin_proposal::handler(ctx)
+    }
+
+    pub fn set_admin_timelock_delay(
+        ctx: Context<SetAdminTimelockDelay>,
+        admin_timelock_delay: u64,
+    ) -> Result<()> {
+        instructions::set_admin_timelock_delay::handler(ctx, admin_timelock_delay)
+    }
+
     pub fn set_whitelist_authority(
         ctx: Context<SetWhitelistAuthority>,
         new_whitelist_authority: Pubkey,
@@ -63,23 +145,114 @@ pub mod borgpad {
         instructions::set_whitelist_authority::handler(ctx, new_whitelist_authority)
     }
 
+    pub fn grant_role(
+        ctx: Context<GrantRole>,
+        role: Role,
+        new_holder: Pubkey,
+    ) -> Result<()> {
+        instructions::grant_role::handler(ctx, role, new_holder)
+    }
+
+    pub fn accept_role(
+        ctx: Context<AcceptRole>,
+        role: Role,
+    ) -> Result<()> {
+        instructions::accept_role::handler(ctx, role)
+    }
+
+    pub fn revoke_role(
+        ctx: Context<RevokeRole>,
+        role: Role,
+    ) -> Result<()> {
+        instructions::revoke_role::handler(ctx, role)
+    }
+
+    pub fn set_paused(
+        ctx: Context<SetPaused>,
+        paused: bool,
+        refunds_exempt_from_pause: bool,
+    ) -> Result<()> {
+        instructions::set_paused::handler(ctx, paused, refunds_exempt_from_pause)
+    }
+
+    pub fn set_lbp_paused(
+        ctx: Context<SetLbpPaused>,
+        paused: bool,
+    ) -> Result<()> {
+        instructions::set_lbp_paused::handler(ctx, paused)
+    }
+
+    pub fn set_platform_fees(
+        ctx: Context<SetPlatformFees>,
+        raised_token_fee_bps: u16,
+        launched_token_fee_bps: u16,
+        fee_treasury: Pubkey,
+    ) -> Result<()> {
+        instructions::set_platform_fees::handler(ctx, raised_token_fee_bps, launched_token_fee_bps, fee_treasury)
+    }
+
+    pub fn set_referral_fees(
+        ctx: Context<SetReferralFees>,
+        referral_raised_token_fee_bps: u16,
+        referral_launched_token_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::set_referral_fees::handler(ctx, referral_raised_token_fee_bps, referral_launched_token_fee_bps)
+    }
+
+    pub fn set_dex_programs(
+        ctx: Context<SetDexPrograms>,
+        raydium_cp_swap_program: Pubkey,
+        orca_whirlpool_program: Pubkey,
+        meteora_damm_program: Pubkey,
+    ) -> Result<()> {
+        instructions::set_dex_programs::handler(ctx, raydium_cp_swap_program, orca_whirlpool_program, meteora_damm_program)
+    }
+
     pub fn move_to_refund_phase(
         ctx: Context<MoveToRefundPhase>,
     ) -> Result<()> {
         instructions::move_to_refund_phase::handler(ctx)
     }
 
-    pub fn move_to_vesting_phase(
-        ctx: Context<MoveToVestingPhase>,
+    pub fn cancel_lbp(
+        ctx: Context<CancelLbp>,
+        reason: CancelReason,
     ) -> Result<()> {
-        instructions::move_to_vesting_phase::handler(ctx)
+        instructions::cancel_lbp::handler(ctx, reason)
+    }
+
+    pub fn set_lbp_manager(
+        ctx: Context<SetLbpManager>,
+        manager: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_lbp_manager::handler(ctx, manager)
+    }
+
+    pub fn set_lbp_whitelist_authority(
+        ctx: Context<SetLbpWhitelistAuthority>,
+        whitelist_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_lbp_whitelist_authority::handler(ctx, whitelist_authority)
+    }
+
+    pub fn finalize_sale(
+        ctx: Context<FinalizeSale>,
+    ) -> Result<()> {
+        instructions::finalize_sale::handler(ctx)
+    }
+
+    pub fn create_pool<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreatePool<'info>>,
+    ) -> Result<()> {
+        instructions::create_pool::handler(ctx)
     }
 
     pub fn user_deposit(
         ctx: Context<UserDeposit>,
-        amount: u64
+        amount: u64,
+        referrer: Option<Pubkey>,
     ) -> Result<()> {
-        instructions::user_deposit::handler(ctx, amount)
+        instructions::user_deposit::handler(ctx, amount, referrer)
     }
 
     pub fn user_refund(
@@ -88,6 +261,12 @@ pub mod borgpad {
         instructions::user_refund::handler(ctx)
     }
 
+    pub fn refund_position(
+        ctx: Context<RefundPosition>,
+    ) -> Result<()> {
+        instructions::refund_position::handler(ctx)
+    }
+
     pub fn project_deposit(
         ctx: Context<ProjectDeposit>,
         amount: u64
@@ -100,4 +279,149 @@ pub mod borgpad {
     ) -> Result<()> {
         instructions::project_refund::handler(ctx)
     }
+
+    pub fn claim_referral_reward(
+        ctx: Context<ClaimReferralReward>,
+    ) -> Result<()> {
+        instructions::claim_referral_reward::handler(ctx)
+    }
+
+    pub fn migrate_lbp_vaults(
+        ctx: Context<MigrateLbpVaults>,
+    ) -> Result<()> {
+        instructions::migrate_lbp_vaults::handler(ctx)
+    }
+
+    pub fn reclaim_pool_funding(
+        ctx: Context<ReclaimPoolFunding>,
+    ) -> Result<()> {
+        instructions::reclaim_pool_funding::handler(ctx)
+    }
+
+    pub fn project_claim_lp(
+        ctx: Context<ProjectClaimLp>,
+    ) -> Result<()> {
+        instructions::project_claim_lp::handler(ctx)
+    }
+
+    pub fn harvest_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, HarvestFees<'info>>,
+    ) -> Result<()> {
+        instructions::harvest_fees::handler(ctx)
+    }
+
+    pub fn claim_position_fees(
+        ctx: Context<ClaimPositionFees>,
+    ) -> Result<()> {
+        instructions::claim_position_fees::handler(ctx)
+    }
+
+    pub fn user_claim(
+        ctx: Context<UserClaim>,
+    ) -> Result<()> {
+        instructions::user_claim::handler(ctx)
+    }
+
+    pub fn project_claim_treasury(
+        ctx: Context<ProjectClaimTreasury>,
+    ) -> Result<()> {
+        instructions::project_claim_treasury::handler(ctx)
+    }
+
+    pub fn request_tranche(
+        ctx: Context<RequestTranche>,
+    ) -> Result<()> {
+        instructions::request_tranche::handler(ctx)
+    }
+
+    pub fn propose_emergency_refund(
+        ctx: Context<ProposeEmergencyRefund>,
+    ) -> Result<()> {
+        instructions::propose_emergency_refund::handler(ctx)
+    }
+
+    pub fn vote_proposal(
+        ctx: Context<VoteProposal>,
+        approve: bool,
+    ) -> Result<()> {
+        instructions::vote_proposal::handler(ctx, approve)
+    }
+
+    pub fn resolve_proposal(
+        ctx: Context<ResolveProposal>,
+    ) -> Result<()> {
+        instructions::resolve_proposal::handler(ctx)
+    }
+
+    pub fn create_grant(
+        ctx: Context<CreateGrant>,
+        beneficiary: Pubkey,
+        amount: u64,
+        schedule: VestingSchedule,
+        revocable: bool,
+    ) -> Result<()> {
+        instructions::create_grant::handler(ctx, beneficiary, amount, schedule, revocable)
+    }
+
+    pub fn claim_grant(
+        ctx: Context<ClaimGrant>,
+    ) -> Result<()> {
+        instructions::claim_grant::handler(ctx)
+    }
+
+    pub fn revoke_grant(
+        ctx: Context<RevokeGrant>,
+    ) -> Result<()> {
+        instructions::revoke_grant::handler(ctx)
+    }
+
+    pub fn close_position(
+        ctx: Context<ClosePosition>,
+    ) -> Result<()> {
+        instructions::close_position::handler(ctx)
+    }
+
+    pub fn close_lbp_vault(
+        ctx: Context<CloseLbpVault>,
+    ) -> Result<()> {
+        instructions::close_lbp_vault::handler(ctx)
+    }
+
+    pub fn close_lbp(
+        ctx: Context<CloseLbp>,
+    ) -> Result<()> {
+        instructions::close_lbp::handler(ctx)
+    }
+
+    pub fn sweep_lbp(
+        ctx: Context<SweepLbp>,
+        destination: SweepDestination,
+    ) -> Result<()> {
+        instructions::sweep_lbp::handler(ctx, destination)
+    }
+
+    pub fn migrate_config(
+        ctx: Context<MigrateConfig>,
+    ) -> Result<()> {
+        instructions::migrate_config::handler(ctx)
+    }
+
+    pub fn migrate_lbp(
+        ctx: Context<MigrateLbp>,
+    ) -> Result<()> {
+        instructions::migrate_lbp::handler(ctx)
+    }
+
+    pub fn migrate_position(
+        ctx: Context<MigratePosition>,
+    ) -> Result<()> {
+        instructions::migrate_position::handler(ctx)
+    }
+
+    pub fn update_lbp(
+        ctx: Context<UpdateLbp>,
+        parameters: LbpParameters,
+    ) -> Result<()> {
+        instructions::update_lbp::handler(ctx, parameters)
+    }
 }
@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_ADMIN_MEMBERS;
+use crate::errors::ErrorCode;
+use crate::state::config::Config;
+
+/// The sensitive admin actions which need the approval of the admin multisig once it is enabled
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, Eq, PartialEq)]
+pub enum AdminAction {
+    InitializeLbp,
+    MoveToRefundPhase,
+    CancelLbp,
+    FinalizeSale,
+    NominateNewAdminAuthority,
+    SetWhitelistAuthority,
+    SetPlatformFees,
+    SetReferralFees,
+    SetDexPrograms,
+    SetAdminMembers,
+    SetAdminTimelockDelay,
+    GrantRole,
+    RevokeRole,
+    SetLbpManager,
+    SetLbpWhitelistAuthority,
+    UpdateLbp,
+    SweepLbp,
+    MigrateLbp,
+    MigrateLbpVaults,
+    MigratePosition,
+    CloseLbpVault,
+    ReclaimPoolFunding,
+    SetPaused,
+    SetLbpPaused,
+    CreatePool,
+}
+
+impl AdminAction {
+    /// Whether the action changes the authorities or the fees of the platform,
+    /// in which case it only takes effect after the timelock delay so that the users can react
+    pub fn is_timelocked(&self) -> bool {
+        matches!(
+            self,
+            AdminAction::NominateNewAdminAuthority
+                | AdminAction::SetWhitelistAuthority
+                | AdminAction::SetPlatformFees
+                | AdminAction::SetReferralFees
+                | AdminAction::SetDexPrograms
+                | AdminAction::SetAdminMembers
+                | AdminAction::SetAdminTimelockDelay
+                | AdminAction::GrantRole
+        )
+    }
+}
+
+/// An admin action proposed to the members of the admin multisig
+#[account]
+#[derive(InitSpace)]
+pub struct AdminProposal {
+    /// The index of the proposal in the config, used to derive its pda
+    pub index: u64,
+    /// The member who proposed the action
+    pub proposer: Pubkey,
+    /// The action to execute
+    pub action: AdminAction,
+    /// The lbp targeted by the action, the default pubkey if the action targets the config
+    pub target: Pubkey,
+    /// The hash of the borsh serialized arguments of the action
+    pub args_hash: [u8; 32],
+    /// The members who approved the proposal
+    #[max_len(MAX_ADMIN_MEMBERS)]
+    pub approvals: Vec<Pubkey>,
+    /// The members other than the proposer who voted to cancel the proposal
+    #[max_len(MAX_ADMIN_MEMBERS)]
+    pub cancellations: Vec<Pubkey>,
+    /// The time from which the action can be executed, set once the proposal is approved
+    /// Expressed as Unix time (i.e. seconds since the Unix epoch).
+    pub eta: Option<u64>,
+    /// Whether the action has been cancelled
+    pub cancelled: bool,
+    /// Whether the action has been executed
+    pub executed: bool,
+    /// The bump of the pda
+    pub bump: u8,
+}
+
+impl AdminProposal {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    pub fn initialize(
+        &mut self,
+        index: u64,
+        proposer: Pubkey,
+        action: AdminAction,
+        target: Pubkey,
+        args_hash: [u8; 32],
+        bump: u8,
+    ) {
+        self.index = index;
+        self.proposer = proposer;
+        self.action = action;
+        self.target = target;
+        self.args_hash = args_hash;
+        // The proposer approves its own proposal
+        self.approvals = vec![proposer];
+        self.cancellations = vec![];
+        self.eta = None;
+        self.cancelled = false;
+        self.executed = false;
+        self.bump = bump;
+    }
+
+    /// Returns the number of approvals given by the current members of the admin multisig
+    pub fn approval_count(&self, config: &Config) -> usize {
+        self.approvals.iter()
+            .filter(|member| config.admin_members.contains(member))
+            .count()
+    }
+
+    /// Records the cancellation of the member, the proposer cancels its own proposal alone
+    /// while the other members must reach the threshold of the admin multisig
+    /// Returns whether the proposal has just been cancelled
+    pub fn cancel(&mut self, config: &Config, member: Pubkey) -> Result<bool> {
+        if member != self.proposer {
+            if self.cancellations.contains(&member) {
+                return err!(ErrorCode::AlreadyCancelled)
+            }
+            self.cancellations.push(member);
+
+            let cancellation_count = self.cancellations.iter()
+                .filter(|member| config.admin_members.contains(member))
+                .count();
+            if cancellation_count < config.admin_threshold as usize {
+                return Ok(false)
+            }
+        }
+
+        self.cancelled = true;
+
+        Ok(true)
+    }
+
+    /// Queues the action once approved, the eta including the timelock delay if the action is timelocked
+    /// Returns whether the action has just been queued
+    pub fn queue_if_approved(&mut self, config: &Config, now: u64) -> bool {
+        if self.eta.is_some() || self.approval_count(config) < config.admin_threshold as usize {
+            return false
+        }
+
+        let delay = if self.action.is_timelocked() { config.admin_timelock_delay } else { 0 };
+        self.eta = Some(now + delay);
+
+        true
+    }
+}
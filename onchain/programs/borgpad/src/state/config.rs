@@ -1,5 +1,12 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{CONFIG_VERSION, MAX_ADMIN_MEMBERS, MAX_ROLES};
+use crate::dex::{meteora_damm, orca_whirlpool, raydium_cp_swap};
+use crate::errors::ErrorCode;
+use crate::state::admin_proposal::{AdminAction, AdminProposal};
+use crate::state::lbp::{Dex, Lbp};
+use crate::state::role::{Role, RoleGrant};
+
 #[account]
 #[derive(InitSpace)]
 pub struct Config {
@@ -7,10 +14,59 @@ pub struct Config {
     pub admin_authority: Pubkey,
     /// The pending new authority that has admin right
     pub pending_admin_authority: Option<Pubkey>,
+    /// The members of the admin multisig, empty while the admin authority acts alone
+    #[max_len(MAX_ADMIN_MEMBERS)]
+    pub admin_members: Vec<Pubkey>,
+    /// The number of distinct members who must approve a sensitive admin action
+    /// 0 while the admin authority acts alone
+    pub admin_threshold: u8,
+    /// The number of admin proposals created, used to derive their pda
+    pub admin_proposal_count: u64,
+    /// The delay between the approval of a timelocked admin action and its execution
+    /// Expressed in seconds
+    pub admin_timelock_delay: u64,
     /// The authority that has whitelist right
     pub whitelist_authority: Pubkey,
+    /// The roles delegated by the admin, one entry per role
+    #[max_len(MAX_ROLES)]
+    pub roles: Vec<RoleGrant>,
+
+    /// Whether all the sales are paused
+    pub paused: bool,
+    /// Whether the refunds stay open while paused so that the users can always exit
+    pub refunds_exempt_from_pause: bool,
+
+    /// The owner of the token accounts receiving the platform fees
+    pub fee_treasury: Pubkey,
+    /// The part of the raised tokens taken as platform fee when a sale succeeds
+    /// Expressed in basis points
+    pub raised_token_fee_bps: u16,
+    /// The part of the launched tokens taken as platform fee when a sale succeeds
+    /// Expressed in basis points
+    pub launched_token_fee_bps: u16,
+    /// The part of the raised token platform fee paid to the referrers
+    /// Expressed in basis points
+    pub referral_raised_token_fee_bps: u16,
+    /// The part of the launched token platform fee paid to the referrers
+    /// Expressed in basis points
+    pub referral_launched_token_fee_bps: u16,
+
+    /// The program id of Raydium CP-Swap
+    pub raydium_cp_swap_program: Pubkey,
+    /// The program id of Orca Whirlpool
+    pub orca_whirlpool_program: Pubkey,
+    /// The program id of Meteora DAMM
+    pub meteora_damm_program: Pubkey,
+
     /// The bump of the pda
     pub bump: u8,
+
+    /// The layout version of the account
+    /// The accounts created before the versioning have the layout of [`LegacyConfigV0`] and no version,
+    /// they are rewritten in the current layout by the migration
+    pub version: u8,
+    /// Space reserved for the fields of the next layouts
+    pub reserved: [u8; 128],
 }
 
 impl Config {
@@ -19,7 +75,172 @@ impl Config {
     pub fn initialize(&mut self, admin_authority: Pubkey, whitelist_authority: Pubkey, bump: u8) {
         self.admin_authority = admin_authority;
         self.pending_admin_authority = None;
+        self.admin_members = vec![];
+        self.admin_threshold = 0;
+        self.admin_proposal_count = 0;
+        self.admin_timelock_delay = 0;
         self.whitelist_authority = whitelist_authority;
+        self.roles = vec![];
+
+        self.paused = false;
+        self.refunds_exempt_from_pause = true;
+
+        self.fee_treasury = admin_authority;
+        self.raised_token_fee_bps = 0;
+        self.launched_token_fee_bps = 0;
+        self.referral_raised_token_fee_bps = 0;
+        self.referral_launched_token_fee_bps = 0;
+
+        self.raydium_cp_swap_program = raydium_cp_swap::ID;
+        self.orca_whirlpool_program = orca_whirlpool::ID;
+        self.meteora_damm_program = meteora_damm::ID;
+
         self.bump = bump;
+
+        self.version = CONFIG_VERSION;
+        self.reserved = [0; 128];
+    }
+
+    /// Returns the program id pinned for the given DEX
+    pub fn dex_program(&self, dex: &Dex) -> Pubkey {
+        match dex {
+            Dex::RaydiumCpSwap => self.raydium_cp_swap_program,
+            Dex::OrcaWhirlpool => self.orca_whirlpool_program,
+            Dex::MeteoraDamm => self.meteora_damm_program,
+        }
+    }
+
+    /// Whether the sensitive admin actions go through the admin multisig
+    pub fn is_multisig(&self) -> bool {
+        self.admin_threshold > 0
+    }
+
+    /// Whether the given key can execute the sensitive admin actions
+    pub fn is_admin(&self, key: Pubkey) -> bool {
+        if self.is_multisig() {
+            self.admin_members.contains(&key)
+        } else {
+            key == self.admin_authority
+        }
+    }
+
+    /// Checks that a sensitive admin action can be executed
+    /// Once the multisig is enabled the action must be proposed and approved by enough members,
+    /// and a timelocked action must wait for its eta. The proposal is then marked as executed
+    pub fn authorize_admin_action(
+        &self,
+        admin_proposal: Option<&mut Account<AdminProposal>>,
+        action: AdminAction,
+        target: Pubkey,
+        args_hash: [u8; 32],
+    ) -> Result<()> {
+        let needs_proposal = self.is_multisig() || action.is_timelocked() && self.admin_timelock_delay > 0;
+        if !needs_proposal {
+            return Ok(())
+        }
+
+        let Some(admin_proposal) = admin_proposal else {
+            return err!(ErrorCode::MissingAdminProposal)
+        };
+
+        if admin_proposal.executed {
+            return err!(ErrorCode::AdminProposalExecuted)
+        }
+
+        if admin_proposal.cancelled {
+            return err!(ErrorCode::AdminProposalCancelled)
+        }
+
+        if admin_proposal.action != action
+            || admin_proposal.target != target
+            || admin_proposal.args_hash != args_hash
+        {
+            return err!(ErrorCode::AdminProposalMismatch)
+        }
+
+        let Some(eta) = admin_proposal.eta else {
+            return err!(ErrorCode::AdminThresholdNotReached)
+        };
+
+        // Only the approvals of the current members count
+        if admin_proposal.approval_count(self) < self.admin_threshold as usize {
+            return err!(ErrorCode::AdminThresholdNotReached)
+        }
+
+        let now = Clock::get()?.unix_timestamp as u64;
+        if now < eta {
+            return err!(ErrorCode::TimelockNotExpired)
+        }
+
+        admin_proposal.executed = true;
+
+        Ok(())
+    }
+
+    /// Whether the given key holds the given role
+    pub fn has_role(&self, role: Role, key: Pubkey) -> bool {
+        self.roles.iter().any(|grant| grant.role == role && grant.holder == Some(key))
+    }
+
+    /// Whether the given key can execute an admin action delegated to the given role
+    pub fn is_admin_or(&self, role: Role, key: Pubkey) -> bool {
+        self.is_admin(key) || self.has_role(role, key)
+    }
+
+    /// Checks that an admin action delegated to the given role can be executed by the given key
+    /// The holder of the role acts alone, unless the action is timelocked, anyone else goes through
+    /// the checks of the admin actions. A timelocked action always waits for its queued proposal
+    pub fn authorize_role_or_admin_action(
+        &self,
+        role: Role,
+        key: Pubkey,
+        admin_proposal: Option<&mut Account<AdminProposal>>,
+        action: AdminAction,
+        target: Pubkey,
+        args_hash: [u8; 32],
+    ) -> Result<()> {
+        if self.has_role(role, key) && !action.is_timelocked() {
+            return Ok(())
+        }
+
+        self.authorize_admin_action(admin_proposal, action, target, args_hash)
+    }
+
+    /// Whether the given key can execute the admin actions on the given lbp
+    pub fn is_lbp_admin(&self, lbp: &Lbp, key: Pubkey) -> bool {
+        lbp.is_manager(key) || self.is_admin_or(Role::SaleOperator, key)
+    }
+
+    /// Checks that an admin action on the given lbp can be executed by the given key
+    /// The manager of the lbp and the sale operator act alone, anyone else goes through the checks
+    /// of the admin actions
+    pub fn authorize_lbp_action(
+        &self,
+        lbp: &Account<Lbp>,
+        key: Pubkey,
+        admin_proposal: Option<&mut Account<AdminProposal>>,
+        action: AdminAction,
+        args_hash: [u8; 32],
+    ) -> Result<()> {
+        if lbp.is_manager(key) {
+            return Ok(())
+        }
+
+        self.authorize_role_or_admin_action(Role::SaleOperator, key, admin_proposal, action, lbp.key(), args_hash)
+    }
+
+    /// Whether the given key can pause and unpause the sales
+    pub fn is_pauser(&self, key: Pubkey) -> bool {
+        self.is_admin_or(Role::Pauser, key)
+    }
+
+    /// Fails if the given lbp, or all the sales, are paused
+    /// Refunds go through when they are exempt from the pause
+    pub fn check_not_paused(&self, lbp: &Lbp, is_refund: bool) -> Result<()> {
+        if (self.paused || lbp.paused) && !(is_refund && self.refunds_exempt_from_pause) {
+            return err!(ErrorCode::Paused)
+        }
+
+        Ok(())
     }
 }
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::state::lbp::Lbp;
+use crate::state::vesting_schedule::VestingSchedule;
+
+/// A vesting grant of launched tokens from the project to a team member or an advisor
+#[account]
+#[derive(InitSpace)]
+pub struct Grant {
+    /// The lbp this grant is linked to, the vesting starts with the one of the lbp
+    pub lbp: Pubkey,
+    /// The index of the grant in the lbp
+    pub index: u32,
+    /// The beneficiary of the grant
+    pub beneficiary: Pubkey,
+    /// The amount of launched token granted
+    /// Reduced to the amount vested at the time of the revocation if the grant is revoked
+    pub amount: u64,
+    /// The amount of launched token already claimed by the beneficiary
+    pub claimed: u64,
+    /// How the grant is unlocked from the start of the vesting
+    pub schedule: VestingSchedule,
+    /// Whether the project can revoke the part of the grant which has not vested yet
+    pub revocable: bool,
+    /// Whether the grant has been revoked
+    pub revoked: bool,
+    /// The bump of the pda
+    pub bump: u8,
+}
+
+impl Grant {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Initializes the next grant of the given lbp
+    pub fn initialize(
+        &mut self,
+        lbp: &Account<Lbp>,
+        beneficiary: Pubkey,
+        amount: u64,
+        schedule: VestingSchedule,
+        revocable: bool,
+        bump: u8,
+    ) {
+        self.lbp = lbp.key();
+        self.index = lbp.grant_count;
+        self.beneficiary = beneficiary;
+        self.amount = amount;
+        self.claimed = 0;
+        self.schedule = schedule;
+        self.revocable = revocable;
+        self.revoked = false;
+        self.bump = bump;
+    }
+
+    /// Returns the amount of launched token vested at `now` for a vesting starting at `start_time`
+    /// A revoked grant is fully vested
+    pub fn vested_amount(&self, start_time: u64, now: u64) -> u64 {
+        if self.revoked {
+            self.amount
+        } else {
+            self.schedule.unlocked_amount(self.amount, start_time, now)
+        }
+    }
+}
@@ -1,13 +1,94 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{BPS_DENOMINATOR, LBP_VERSION};
+use crate::errors::ErrorCode;
+use crate::state::config::Config;
+use crate::state::position::Position;
+use crate::state::vesting_schedule::VestingSchedule;
+use crate::utils::mul_div;
+
 /// Valid phase change:
-/// FundCollection -> Refund
+/// FundCollection -> Refund (also when the sale is cancelled by the admin)
 /// FundCollection -> Vesting
+/// Vesting -> EmergencyRefund
 #[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, Eq, PartialEq)]
 pub enum Phase {
     FundCollection,
     Refund,
     Vesting,
+    /// The position holders voted to return the unreleased treasury to the positions
+    EmergencyRefund,
+}
+
+/// Why the admin cancelled a sale
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, Eq, PartialEq)]
+pub enum CancelReason {
+    ProjectWithdrawal,
+    Fraud,
+    Compliance,
+    Other,
+}
+
+/// The status of the liquidity pool during the vesting phase
+/// Valid status change:
+/// PoolPending -> PoolCreated
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, Eq, PartialEq)]
+pub enum PoolStatus {
+    PoolPending,
+    PoolCreated,
+}
+
+/// How the lp tokens received at pool creation are released to the project
+/// The DEXes with concentrated liquidity hold the liquidity in a position nft, which cannot be unlocked
+/// partially, and is kept locked forever instead of being burnt
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, Eq, PartialEq)]
+pub enum LpLockPolicy {
+    /// The lp tokens are burnt, locking the liquidity forever
+    Burn,
+    /// The lp tokens are released at once after a duration
+    TimeLock { duration: u64 },
+    /// The lp tokens are released linearly after a cliff
+    LinearUnlock { cliff_duration: u64, unlock_duration: u64 },
+}
+
+/// How the trading fees harvested from the liquidity pool are split
+/// The holders of the positions receive the part left to neither the project nor the treasury
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, Eq, PartialEq)]
+pub struct TradingFeeSplit {
+    /// The part of the fees sent to the project, in basis points
+    pub project_bps: u16,
+    /// The part of the fees sent to the fee treasury, in basis points
+    pub treasury_bps: u16,
+}
+
+/// How the project treasury is released to the project
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, Eq, PartialEq)]
+pub enum TreasuryRelease {
+    /// The treasury is released linearly after a cliff, counted from the opening of the trading
+    Vesting { cliff_duration: u64, vesting_duration: u64 },
+    /// The treasury is released by equal tranches, each requested by the project and approved by
+    /// the position holders
+    Milestones { tranche_count: u8 },
+}
+
+/// The rules of the votes of the position holders, weighted by the amount of their positions
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceRules {
+    /// The duration of the voting window of a proposal
+    /// Expressed as Unix time (i.e. seconds since the Unix epoch).
+    pub voting_duration: u64,
+    /// The part of the deposited amount which must vote for a proposal to be valid, in basis points
+    pub quorum_bps: u16,
+    /// The part of the votes which must approve a proposal for it to pass, in basis points
+    pub threshold_bps: u16,
+}
+
+/// The DEX on which the liquidity pool is created
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, Eq, PartialEq)]
+pub enum Dex {
+    RaydiumCpSwap,
+    OrcaWhirlpool,
+    MeteoraDamm,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, Eq, PartialEq)]
@@ -32,13 +113,115 @@ pub struct LbpInitializeData {
     pub raised_token_min_cap: u64,
     /// The max amount of token that the users can deposit
     pub raised_token_max_cap: u64,
-    
-    /// The duration of the cliff phase
+    /// The part of the raised token, in percent, that is used for the liquidity pool. The rest is sent to the project treasury
+    pub raised_token_lp_distribution: u8,
+
+    /// The DEX on which the liquidity pool is created
+    pub dex: Dex,
+    /// The time from which the trading is open on the liquidity pool
     /// Expressed as Unix time (i.e. seconds since the Unix epoch).
-    pub cliff_duration: u64,
-    /// The duration of the vesting phase
+    pub pool_open_time: u64,
+    /// How the lp tokens are released to the project
+    /// Durations are counted from the opening of the trading
+    pub lp_lock_policy: LpLockPolicy,
+    /// How the trading fees of the liquidity pool are split
+    pub trading_fee_split: TradingFeeSplit,
+
+    /// How the launched tokens of the users are unlocked from the start of the vesting
+    pub vesting_schedule: VestingSchedule,
+
+    /// How the project treasury is released to the project
+    pub treasury_release: TreasuryRelease,
+    /// The rules of the votes of the position holders
+    pub governance: GovernanceRules,
+
+    /// The time after which whatever is left unclaimed in the lbp can be swept by the admin
     /// Expressed as Unix time (i.e. seconds since the Unix epoch).
-    pub vesting_duration: u64,
+    pub claim_deadline: u64,
+}
+
+/// The parameters of an lbp which can still be changed until it receives its first deposit
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct LbpParameters {
+    pub launched_token_lp_distribution: u8,
+    pub launched_token_cap: u64,
+
+    pub raised_token_min_cap: u64,
+    pub raised_token_max_cap: u64,
+    pub raised_token_lp_distribution: u8,
+
+    pub dex: Dex,
+    pub pool_open_time: u64,
+    pub lp_lock_policy: LpLockPolicy,
+    pub trading_fee_split: TradingFeeSplit,
+
+    pub vesting_schedule: VestingSchedule,
+
+    pub treasury_release: TreasuryRelease,
+    pub governance: GovernanceRules,
+
+    pub claim_deadline: u64,
+}
+
+impl From<&LbpInitializeData> for LbpParameters {
+    fn from(lbp_initialize: &LbpInitializeData) -> Self {
+        Self {
+            launched_token_lp_distribution: lbp_initialize.launched_token_lp_distribution,
+            launched_token_cap: lbp_initialize.launched_token_cap,
+            raised_token_min_cap: lbp_initialize.raised_token_min_cap,
+            raised_token_max_cap: lbp_initialize.raised_token_max_cap,
+            raised_token_lp_distribution: lbp_initialize.raised_token_lp_distribution,
+            dex: lbp_initialize.dex.clone(),
+            pool_open_time: lbp_initialize.pool_open_time,
+            lp_lock_policy: lbp_initialize.lp_lock_policy.clone(),
+            trading_fee_split: lbp_initialize.trading_fee_split.clone(),
+            vesting_schedule: lbp_initialize.vesting_schedule.clone(),
+            treasury_release: lbp_initialize.treasury_release.clone(),
+            governance: lbp_initialize.governance.clone(),
+            claim_deadline: lbp_initialize.claim_deadline,
+        }
+    }
+}
+
+impl LbpParameters {
+    pub fn validate(&self) -> Result<()> {
+        if self.raised_token_min_cap > self.raised_token_max_cap {
+            return err!(ErrorCode::InvalidCaps);
+        }
+
+        // A position nft cannot be unlocked partially
+        if self.dex != Dex::RaydiumCpSwap && matches!(self.lp_lock_policy, LpLockPolicy::LinearUnlock { .. }) {
+            return err!(ErrorCode::InvalidLpLockPolicy);
+        }
+
+        self.vesting_schedule.validate()?;
+
+        if self.launched_token_lp_distribution > 100 || self.raised_token_lp_distribution > 100 {
+            return err!(ErrorCode::InvalidLpDistribution);
+        }
+
+        if let TreasuryRelease::Milestones { tranche_count } = self.treasury_release {
+            if tranche_count == 0 {
+                return err!(ErrorCode::InvalidTreasuryRelease);
+            }
+        }
+
+        let governance = &self.governance;
+        if governance.quorum_bps as u64 > BPS_DENOMINATOR || governance.threshold_bps as u64 > BPS_DENOMINATOR {
+            return err!(ErrorCode::InvalidGovernanceRules);
+        }
+
+        let trading_fee_split = &self.trading_fee_split;
+        if trading_fee_split.project_bps as u64 + trading_fee_split.treasury_bps as u64 > BPS_DENOMINATOR {
+            return err!(ErrorCode::InvalidFeeSplit);
+        }
+
+        if self.claim_deadline <= self.pool_open_time {
+            return err!(ErrorCode::InvalidClaimDeadline);
+        }
+
+        Ok(())
+    }
 }
 
 #[account]
@@ -49,6 +232,8 @@ pub struct Lbp {
 
     /// The owner of the lbp, i.e., the project launching the token
     pub project: Pubkey,
+    /// The account which paid the rent of the lbp and its vaults, refunded once they are closed
+    pub payer: Pubkey,
 
     /// The mint of the token supplied by the project
     pub launched_token_mint: Pubkey,
@@ -58,6 +243,24 @@ pub struct Lbp {
     pub launched_token_lp_distribution: u8,
     /// The amount of token that the project must deposit
     pub launched_token_cap: u64,
+    /// The amount of launched token taken as platform fee
+    /// Set by the program once the sale is finalized
+    pub launched_token_fee: u64,
+    /// The part of the launched token fee kept in the lbp to reward the referrers
+    /// Set by the program once the sale is finalized
+    pub launched_token_referral_reward: u64,
+    /// The launched tokens not used for the liquidity pool, vested to the positions pro rata of their deposit
+    /// Set by the program once the sale is finalized
+    pub launched_token_reward: u64,
+    /// The amount of launched token already claimed by the positions
+    pub launched_token_reward_claimed: u64,
+    /// The amount of launched token granted by the project and not yet claimed nor revoked
+    pub launched_token_granted: u64,
+    /// The number of grants created under the lbp, used to derive the grant pdas
+    pub grant_count: u32,
+    /// The amount of launched token deposited by the project
+    /// Set by the program on the deposit of the project
+    pub launched_token_deposited: u64,
 
     /// The mint of the token supplied by the users
     pub raised_token_mint: Pubkey,
@@ -68,25 +271,117 @@ pub struct Lbp {
     pub raised_token_min_cap: u64,
     /// The max amount of token that the users can deposit
     pub raised_token_max_cap: u64,
+    /// The part of the raised token, in percent, that is used for the liquidity pool. The rest is sent to the project treasury
+    pub raised_token_lp_distribution: u8,
     /// The amount of token that the users have deposited
     /// This amount is greater than raised_token_min_cap and smaller than raised_token_max_cap
+    /// Set by the program once the sale is finalized, from the recorded deposits
     pub raised_token_cap: u64,
+    /// The amount of raised token taken as platform fee
+    /// Set by the program once the sale is finalized
+    pub raised_token_fee: u64,
+    /// The part of the raised token fee kept in the lbp to reward the referrers
+    /// Set by the program once the sale is finalized
+    pub raised_token_referral_reward: u64,
+    /// The amount of token deposited by users that came with a referrer
+    pub referred_token_cap: u64,
+    /// The amount of referred token whose referrers already claimed their reward
+    pub referred_token_claimed: u64,
+    /// The amount of raised token deposited by the users during the fund collection
+    /// Unlike raised_token_cap, it is kept up to date on each deposit, and reduced as the positions are closed
+    /// or refunded
+    pub raised_token_deposited: u64,
+    /// The amount of raised token kept in the lbp for the project treasury
+    /// Set by the program once the sale is finalized
+    pub raised_token_treasury: u64,
+    /// The amount of raised token already released to the project from the treasury
+    pub raised_token_treasury_claimed: u64,
+    /// The number of treasury tranches released to the project
+    pub treasury_tranches_released: u8,
+    /// The number of proposals submitted to the position holders, used to derive the proposal pdas
+    pub proposal_count: u32,
+    /// Whether a tranche request is being voted, only one can be open at a time
+    pub open_proposal: bool,
+    /// The amount of raised token returned pro rata to the positions
+    /// Set by the program once the position holders vote for an emergency refund, and reduced as the
+    /// positions are refunded
+    pub emergency_refund_amount: u64,
+
+    /// The DEX on which the liquidity pool is created
+    pub dex: Dex,
+    /// The time from which the trading is open on the liquidity pool
+    /// Expressed as Unix time (i.e. seconds since the Unix epoch).
+    pub pool_open_time: u64,
+    /// How the lp tokens are released to the project
+    /// Durations are counted from the opening of the trading
+    pub lp_lock_policy: LpLockPolicy,
+    /// How the trading fees of the liquidity pool are split
+    pub trading_fee_split: TradingFeeSplit,
+    /// The mint of the lp tokens, or of the position nft
+    /// Set by the program once the liquidity pool is created
+    pub lp_mint: Pubkey,
+    /// The amount of lp tokens locked for the project
+    /// Set by the program once the liquidity pool is created, and reduced by the lp tokens withdrawn
+    /// to harvest the trading fees
+    pub lp_token_amount: u64,
+    /// The amount of lp tokens already claimed by the project
+    pub lp_token_claimed: u64,
+    /// The value of one lp token at the last harvest in Q64.64, i.e., sqrt(k) / lp supply
+    /// Only used by the DEXes accruing the trading fees in the reserves, zero until the first harvest
+    pub lp_value_x64: u128,
+    /// The launched token fees harvested per raised token deposited by the open positions, in Q64.64
+    pub launched_token_fee_per_share_x64: u128,
+    /// The raised token fees harvested per raised token deposited by the open positions, in Q64.64
+    pub raised_token_fee_per_share_x64: u128,
+    /// The launched token fees harvested for the position holders and not yet claimed
+    pub launched_token_holder_fees: u64,
+    /// The raised token fees harvested for the position holders and not yet claimed
+    pub raised_token_holder_fees: u64,
 
     /// The current phase of the lbp
     pub phase: Phase,
-    /// The start time of the cliff phase
-    /// Set by the program once the admin transition from fund collection to cliff phase
+    /// The status of the liquidity pool during the vesting phase
+    pub pool_status: PoolStatus,
+    /// The start time of the vesting
+    /// Set by the program once the liquidity pool is created, and not before the trading opens
     /// Expressed as Unix time (i.e. seconds since the Unix epoch).
     pub vesting_start_time: u64,
-    /// The duration of the cliff phase
-    /// Expressed as Unix time (i.e. seconds since the Unix epoch).
-    pub cliff_duration: u64,
-    /// The duration of the vesting phase
+    /// How the launched tokens of the users are unlocked from the start of the vesting
+    pub vesting_schedule: VestingSchedule,
+
+    /// How the project treasury is released to the project
+    pub treasury_release: TreasuryRelease,
+    /// The rules of the votes of the position holders
+    pub governance: GovernanceRules,
+
+    /// The time after which whatever is left unclaimed in the lbp can be swept by the admin
     /// Expressed as Unix time (i.e. seconds since the Unix epoch).
-    pub vesting_duration: u64,
+    pub claim_deadline: u64,
+
+    /// The authority whitelisting the deposits of this sale, the one of the config if unset
+    pub whitelist_authority: Option<Pubkey>,
+    /// The key managing this sale on behalf of the admin, if any
+    pub manager: Option<Pubkey>,
+
+    /// Whether this sale is paused
+    pub paused: bool,
+    /// Why the admin cancelled the sale, if it did
+    pub cancel_reason: Option<CancelReason>,
+
+    /// The number of positions not closed yet
+    pub position_count: u32,
+    /// The number of token accounts of the lbp not closed yet
+    pub vault_count: u8,
 
     /// The bump of the pda
     pub bump: u8,
+
+    /// The layout version of the account
+    /// The accounts created before the versioning have the layout of [`LegacyLbpV0`] and no version,
+    /// they are rewritten in the current layout by the migration
+    pub version: u8,
+    /// Space reserved for the fields of the next layouts
+    pub reserved: [u8; 256],
 }
 
 impl Lbp {
@@ -95,11 +390,13 @@ impl Lbp {
     pub fn initialize(
         &mut self,
         lbp_initialize: LbpInitializeData,
+        payer: Pubkey,
         launched_token_ata: Pubkey,
         raised_token_ata: Pubkey,
         bump: u8,
     ) {
         self.uid = lbp_initialize.uid;
+        self.payer = payer;
 
         self.project = lbp_initialize.project;
 
@@ -107,18 +404,363 @@ impl Lbp {
         self.launched_token_ata = launched_token_ata;
         self.launched_token_lp_distribution = lbp_initialize.launched_token_lp_distribution;
         self.launched_token_cap = lbp_initialize.launched_token_cap;
+        self.launched_token_fee = 0;
+        self.launched_token_referral_reward = 0;
+        self.launched_token_reward = 0;
+        self.launched_token_reward_claimed = 0;
+        self.launched_token_granted = 0;
+        self.grant_count = 0;
+        self.launched_token_deposited = 0;
 
         self.raised_token_mint = lbp_initialize.raised_token_mint;
         self.raised_token_ata = raised_token_ata;
         self.raised_token_min_cap = lbp_initialize.raised_token_min_cap;
         self.raised_token_max_cap = lbp_initialize.raised_token_max_cap;
+        self.raised_token_lp_distribution = lbp_initialize.raised_token_lp_distribution;
         self.raised_token_cap = 0;
+        self.raised_token_fee = 0;
+        self.raised_token_referral_reward = 0;
+        self.referred_token_cap = 0;
+        self.referred_token_claimed = 0;
+        self.raised_token_deposited = 0;
+        self.raised_token_treasury = 0;
+        self.raised_token_treasury_claimed = 0;
+        self.treasury_tranches_released = 0;
+        self.proposal_count = 0;
+        self.open_proposal = false;
+        self.emergency_refund_amount = 0;
+
+        self.dex = lbp_initialize.dex;
+        self.pool_open_time = lbp_initialize.pool_open_time;
+        self.lp_lock_policy = lbp_initialize.lp_lock_policy;
+        self.lp_mint = Pubkey::default();
+        self.lp_token_amount = 0;
+        self.lp_token_claimed = 0;
+        self.trading_fee_split = lbp_initialize.trading_fee_split;
+        self.lp_value_x64 = 0;
+        self.launched_token_fee_per_share_x64 = 0;
+        self.raised_token_fee_per_share_x64 = 0;
+        self.launched_token_holder_fees = 0;
+        self.raised_token_holder_fees = 0;
 
         self.phase = Phase::FundCollection;
+        self.pool_status = PoolStatus::PoolPending;
         self.vesting_start_time = u64::MAX;
-        self.cliff_duration = lbp_initialize.cliff_duration;
-        self.vesting_duration = lbp_initialize.vesting_duration;
+        self.vesting_schedule = lbp_initialize.vesting_schedule;
+        self.treasury_release = lbp_initialize.treasury_release;
+        self.governance = lbp_initialize.governance;
+        self.claim_deadline = lbp_initialize.claim_deadline;
+
+        self.whitelist_authority = None;
+        self.manager = None;
+
+        self.paused = false;
+        self.cancel_reason = None;
+
+        self.position_count = 0;
+        self.vault_count = 2;
 
         self.bump = bump;
+
+        self.version = LBP_VERSION;
+        self.reserved = [0; 256];
+    }
+    /// Returns the parameters which can still be changed until the lbp receives its first deposit
+    pub fn parameters(&self) -> LbpParameters {
+        LbpParameters {
+            launched_token_lp_distribution: self.launched_token_lp_distribution,
+            launched_token_cap: self.launched_token_cap,
+            raised_token_min_cap: self.raised_token_min_cap,
+            raised_token_max_cap: self.raised_token_max_cap,
+            raised_token_lp_distribution: self.raised_token_lp_distribution,
+            dex: self.dex.clone(),
+            pool_open_time: self.pool_open_time,
+            lp_lock_policy: self.lp_lock_policy.clone(),
+            trading_fee_split: self.trading_fee_split.clone(),
+            vesting_schedule: self.vesting_schedule.clone(),
+            treasury_release: self.treasury_release.clone(),
+            governance: self.governance.clone(),
+            claim_deadline: self.claim_deadline,
+        }
+    }
+
+    pub fn update(&mut self, parameters: LbpParameters) {
+        self.launched_token_lp_distribution = parameters.launched_token_lp_distribution;
+        self.launched_token_cap = parameters.launched_token_cap;
+        self.raised_token_min_cap = parameters.raised_token_min_cap;
+        self.raised_token_max_cap = parameters.raised_token_max_cap;
+        self.raised_token_lp_distribution = parameters.raised_token_lp_distribution;
+        self.dex = parameters.dex;
+        self.pool_open_time = parameters.pool_open_time;
+        self.lp_lock_policy = parameters.lp_lock_policy;
+        self.trading_fee_split = parameters.trading_fee_split;
+        self.vesting_schedule = parameters.vesting_schedule;
+        self.treasury_release = parameters.treasury_release;
+        self.governance = parameters.governance;
+        self.claim_deadline = parameters.claim_deadline;
+    }
+
+    /// Returns the amount of lp tokens released to the project at `now` according to the lock policy
+    /// Nothing is released before the liquidity pool is created
+    pub fn lp_token_unlocked_amount(&self, now: u64) -> u64 {
+        // The vesting starts when the trading opens on the pool
+        let open_time = self.vesting_start_time;
+
+        match self.lp_lock_policy {
+            LpLockPolicy::Burn => 0,
+            LpLockPolicy::TimeLock { duration } => {
+                if now >= open_time.saturating_add(duration) {
+                    self.lp_token_amount
+                } else {
+                    0
+                }
+            }
+            LpLockPolicy::LinearUnlock { cliff_duration, unlock_duration } => {
+                VestingSchedule::Linear { cliff_duration, vesting_duration: unlock_duration }
+                    .unlocked_amount(self.lp_token_amount, open_time, now)
+            }
+        }
+    }
+
+    /// Returns the amount of launched tokens vested to a position which deposited `deposited_amount`
+    pub fn launched_token_share(&self, deposited_amount: u64) -> u64 {
+        mul_div(self.launched_token_reward, deposited_amount, self.raised_token_cap)
+    }
+
+    /// Returns the amount of launched tokens unlocked at `now` for a position which deposited `deposited_amount`
+    /// Nothing is unlocked before the liquidity pool is created
+    pub fn launched_token_unlocked_amount(&self, deposited_amount: u64, now: u64) -> u64 {
+        self.vesting_schedule
+            .unlocked_amount(self.launched_token_share(deposited_amount), self.vesting_start_time, now)
+    }
+
+    /// Returns the amount of raised tokens released to the project from the treasury at `now`
+    /// Nothing is released before the liquidity pool is created, and the milestones are released by votes
+    pub fn treasury_unlocked_amount(&self, now: u64) -> u64 {
+        match self.treasury_release {
+            TreasuryRelease::Vesting { cliff_duration, vesting_duration } => {
+                VestingSchedule::Linear { cliff_duration, vesting_duration }
+                    .unlocked_amount(self.raised_token_treasury, self.vesting_start_time, now)
+            }
+            TreasuryRelease::Milestones { .. } => 0,
+        }
+    }
+
+    /// Returns the amount of raised tokens of the next treasury tranche, the last one taking the rounding
+    pub fn next_treasury_tranche_amount(&self, tranche_count: u8) -> u64 {
+        if self.treasury_tranches_released + 1 >= tranche_count {
+            self.raised_token_treasury - self.raised_token_treasury_claimed
+        } else {
+            self.raised_token_treasury / tranche_count as u64
+        }
+    }
+
+    /// Returns the authority whitelisting the deposits, falling back to the one of the config
+    pub fn whitelist_authority(&self, config: &Config) -> Pubkey {
+        self.whitelist_authority.unwrap_or(config.whitelist_authority)
+    }
+
+    /// Whether the given key is the manager of this sale
+    pub fn is_manager(&self, key: Pubkey) -> bool {
+        self.manager == Some(key)
+    }
+
+    /// Returns the amounts of launched and raised tokens the lbp still owes, which the sweep leaves in its vaults
+    pub fn reserved_amounts(&self) -> (u64, u64) {
+        // The grants keep vesting and the harvested trading fees belong to the position holders in any phase
+        let mut launched_token_reserved = self.launched_token_granted + self.launched_token_holder_fees;
+        let mut raised_token_reserved = self.raised_token_holder_fees;
+
+        match self.phase {
+            // The deposits are owed back to the project and to the positions until refunded
+            Phase::FundCollection | Phase::Refund => {
+                launched_token_reserved += self.launched_token_deposited;
+                raised_token_reserved += self.raised_token_deposited;
+            }
+            // The launched tokens of the positions, the referral rewards and the unreleased treasury
+            Phase::Vesting => {
+                let unclaimed_referral_reward = |reward: u64| {
+                    reward - mul_div(reward, self.referred_token_claimed, self.referred_token_cap)
+                };
+
+                launched_token_reserved += self.launched_token_reward - self.launched_token_reward_claimed
+                    + unclaimed_referral_reward(self.launched_token_referral_reward);
+                raised_token_reserved += self.raised_token_treasury - self.raised_token_treasury_claimed
+                    + unclaimed_referral_reward(self.raised_token_referral_reward);
+            }
+            // What is left of the unreleased treasury is owed to the positions not refunded yet
+            Phase::EmergencyRefund => {
+                raised_token_reserved += self.emergency_refund_amount;
+            }
+        }
+
+        (launched_token_reserved, raised_token_reserved)
+    }
+
+    /// Settles the refund of a position, returning the amount of raised tokens refunded to it
+    /// After a failed sale the deposits are refunded, after an emergency refund what is left of the unreleased
+    /// treasury is refunded pro rata of the deposits of the positions still open
+    /// The trading fees left unclaimed by the position are released to the sweep
+    pub fn settle_refund(&mut self, position: &Position) -> Result<u64> {
+        let amount = match self.phase {
+            Phase::Refund => position.amount,
+            Phase::EmergencyRefund => {
+                let amount = mul_div(self.emergency_refund_amount, position.amount, self.raised_token_deposited);
+                self.emergency_refund_amount -= amount;
+                amount
+            }
+            _ => return err!(ErrorCode::UnauthorisedOperationInCurrentPhase),
+        };
+
+        let (launched_token_fees, raised_token_fees) = position.pending_fees(self);
+        self.launched_token_holder_fees -= launched_token_fees;
+        self.raised_token_holder_fees -= raised_token_fees;
+
+        // The position is closed along with the refund
+        self.raised_token_deposited = self.raised_token_deposited
+            .checked_sub(position.amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.position_count = self.position_count
+            .checked_sub(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        Ok(amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::vesting_schedule::UnlockPoint;
+
+    const START: u64 = 1_000;
+
+    /// Returns a vesting lbp rewarding 1_000_000 launched tokens for 500_000 raised tokens
+    fn vesting_lbp(vesting_schedule: VestingSchedule) -> Lbp {
+        let mut lbp = Lbp::try_deserialize_unchecked(&mut &vec![0; Lbp::LEN][..]).unwrap();
+        lbp.phase = Phase::Vesting;
+        lbp.raised_token_cap = 500_000;
+        lbp.launched_token_reward = 1_000_000;
+        lbp.vesting_start_time = START;
+        lbp.vesting_schedule = vesting_schedule;
+        lbp
+    }
+
+    #[test]
+    fn nothing_is_unlocked_before_the_pool_is_created() {
+        let mut lbp = vesting_lbp(VestingSchedule::Linear { cliff_duration: 0, vesting_duration: 0 });
+        lbp.vesting_start_time = u64::MAX;
+        assert_eq!(lbp.launched_token_unlocked_amount(100_000, START), 0);
+        assert_eq!(lbp.launched_token_unlocked_amount(100_000, u64::MAX - 1), 0);
+    }
+
+    #[test]
+    fn linear_schedule_unlocks_the_positions() {
+        let lbp = vesting_lbp(VestingSchedule::Linear { cliff_duration: 100, vesting_duration: 200 });
+        assert_eq!(lbp.launched_token_unlocked_amount(100_000, START + 99), 0);
+        assert_eq!(lbp.launched_token_unlocked_amount(100_000, START + 200), 100_000);
+        assert_eq!(lbp.launched_token_unlocked_amount(100_000, START + 300), 200_000);
+        assert_eq!(lbp.launched_token_unlocked_amount(500_000, START + 300), 1_000_000);
+    }
+
+    #[test]
+    fn stepwise_schedule_unlocks_the_positions() {
+        let lbp = vesting_lbp(VestingSchedule::Stepwise { cliff_duration: 100, step_duration: 30, step_count: 4 });
+        assert_eq!(lbp.launched_token_unlocked_amount(100_000, START + 129), 0);
+        assert_eq!(lbp.launched_token_unlocked_amount(100_000, START + 130), 50_000);
+        assert_eq!(lbp.launched_token_unlocked_amount(100_000, START + 189), 100_000);
+        assert_eq!(lbp.launched_token_unlocked_amount(100_000, START + 220), 200_000);
+    }
+
+    #[test]
+    fn tge_then_linear_schedule_unlocks_the_positions() {
+        let lbp = vesting_lbp(VestingSchedule::TgeThenLinear {
+            tge_bps: 2_000,
+            cliff_duration: 100,
+            vesting_duration: 100,
+        });
+        assert_eq!(lbp.launched_token_unlocked_amount(100_000, START), 40_000);
+        assert_eq!(lbp.launched_token_unlocked_amount(100_000, START + 150), 120_000);
+        assert_eq!(lbp.launched_token_unlocked_amount(100_000, START + 200), 200_000);
+    }
+
+    #[test]
+    fn custom_schedule_unlocks_the_positions() {
+        let lbp = vesting_lbp(VestingSchedule::Custom {
+            points: vec![
+                UnlockPoint { offset: 0, bps: 1_000 },
+                UnlockPoint { offset: 100, bps: 4_000 },
+                UnlockPoint { offset: 300, bps: 10_000 },
+            ],
+        });
+        assert_eq!(lbp.launched_token_unlocked_amount(100_000, START), 20_000);
+        assert_eq!(lbp.launched_token_unlocked_amount(100_000, START + 100), 80_000);
+        assert_eq!(lbp.launched_token_unlocked_amount(100_000, START + 300), 200_000);
+    }
+
+    /// Returns an open position which deposited `amount`
+    fn position(amount: u64) -> Position {
+        let mut position = Position::try_deserialize_unchecked(&mut &vec![0; Position::LEN][..]).unwrap();
+        position.amount = amount;
+        position
+    }
+
+    #[test]
+    fn refund_returns_the_deposits() {
+        let mut lbp = vesting_lbp(VestingSchedule::Linear { cliff_duration: 0, vesting_duration: 0 });
+        lbp.phase = Phase::Refund;
+        lbp.raised_token_deposited = 500_000;
+        lbp.position_count = 2;
+
+        assert_eq!(lbp.reserved_amounts().1, 500_000);
+        assert_eq!(lbp.settle_refund(&position(400_000)).unwrap(), 400_000);
+        assert_eq!(lbp.reserved_amounts().1, 100_000);
+        assert_eq!(lbp.position_count, 1);
+    }
+
+    #[test]
+    fn emergency_refund_shares_the_whole_treasury() {
+        let mut lbp = vesting_lbp(VestingSchedule::Linear { cliff_duration: 0, vesting_duration: 0 });
+        lbp.phase = Phase::EmergencyRefund;
+        lbp.emergency_refund_amount = 1_000;
+        lbp.raised_token_deposited = 3;
+        lbp.position_count = 3;
+
+        assert_eq!(lbp.settle_refund(&position(1)).unwrap(), 333);
+        assert_eq!(lbp.reserved_amounts().1, 667);
+        assert_eq!(lbp.settle_refund(&position(1)).unwrap(), 333);
+        assert_eq!(lbp.settle_refund(&position(1)).unwrap(), 334);
+        assert_eq!(lbp.reserved_amounts(), (0, 0));
+        assert!(lbp.settle_refund(&position(1)).is_err());
+    }
+
+    #[test]
+    fn refund_releases_the_unclaimed_trading_fees() {
+        let mut lbp = vesting_lbp(VestingSchedule::Linear { cliff_duration: 0, vesting_duration: 0 });
+        lbp.phase = Phase::EmergencyRefund;
+        lbp.raised_token_deposited = 4;
+        lbp.position_count = 1;
+        lbp.raised_token_fee_per_share_x64 = 25 << 64;
+        lbp.raised_token_holder_fees = 100;
+
+        lbp.settle_refund(&position(3)).unwrap();
+        assert_eq!(lbp.raised_token_holder_fees, 25);
+    }
+
+    #[test]
+    fn vesting_reserves_the_unclaimed_entitlements() {
+        let mut lbp = vesting_lbp(VestingSchedule::Linear { cliff_duration: 0, vesting_duration: 0 });
+        lbp.launched_token_reward_claimed = 400_000;
+        lbp.launched_token_granted = 10;
+        lbp.launched_token_holder_fees = 20;
+        lbp.raised_token_holder_fees = 30;
+        lbp.raised_token_treasury = 100_000;
+        lbp.raised_token_treasury_claimed = 40_000;
+        lbp.referred_token_cap = 300;
+        lbp.referred_token_claimed = 100;
+        lbp.launched_token_referral_reward = 3_000;
+        lbp.raised_token_referral_reward = 1_500;
+
+        assert_eq!(lbp.reserved_amounts(), (600_000 + 10 + 20 + 2_000, 60_000 + 30 + 1_000));
     }
 }
@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::errors::ErrorCode;
+use crate::state::config::Config;
+use crate::state::lbp::{
+    Dex, GovernanceRules, Lbp, LbpInitializeData, LpLockPolicy, Phase, TradingFeeSplit, TreasuryRelease,
+};
+use crate::state::position::Position;
+use crate::state::vesting_schedule::VestingSchedule;
+
+/// The layout of the config created before the versioning
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, Eq, PartialEq)]
+pub struct LegacyConfigV0 {
+    pub admin_authority: Pubkey,
+    pub pending_admin_authority: Option<Pubkey>,
+    pub whitelist_authority: Pubkey,
+    pub bump: u8,
+}
+
+impl LegacyConfigV0 {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        check_program_account(account)?;
+        Self::from_data(&account.try_borrow_data()?)
+    }
+
+    pub fn from_data(data: &[u8]) -> Result<Self> {
+        load_legacy_data(data, &Config::DISCRIMINATOR, Self::LEN)
+    }
+
+    /// Initializes the config in the current layout, the fields added since are left to their defaults
+    pub fn upgrade(&self, config: &mut Config) {
+        config.initialize(self.admin_authority, self.whitelist_authority, self.bump);
+        config.pending_admin_authority = self.pending_admin_authority;
+    }
+}
+
+/// The layout of the lbps created before their token accounts were owned by a vault authority
+/// Only read and written back by the migrations
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, Eq, PartialEq)]
+pub struct LegacyLbpV0 {
+    pub uid: u64,
+
+    pub project: Pubkey,
+
+    pub launched_token_mint: Pubkey,
+    pub launched_token_ata: Pubkey,
+    pub launched_token_lp_distribution: u8,
+    pub launched_token_cap: u64,
+
+    pub raised_token_mint: Pubkey,
+    pub raised_token_ata: Pubkey,
+    pub raised_token_min_cap: u64,
+    pub raised_token_max_cap: u64,
+    pub raised_token_cap: u64,
+
+    pub phase: Phase,
+    pub vesting_start_time: u64,
+    pub cliff_duration: u64,
+    pub vesting_duration: u64,
+
+    pub bump: u8,
+}
+
+impl LegacyLbpV0 {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        check_program_account(account)?;
+        Self::from_data(&account.try_borrow_data()?)
+    }
+
+    pub fn from_data(data: &[u8]) -> Result<Self> {
+        load_legacy_data(data, &Lbp::DISCRIMINATOR, Self::LEN)
+    }
+
+    pub fn save(&self, account: &AccountInfo) -> Result<()> {
+        save_legacy_account(self, account)
+    }
+
+    /// Initializes the lbp in the current layout with the parameters the legacy program applied:
+    /// the whole deposits go to a Raydium pool, whose lp tokens and trading fees are not shared,
+    /// and without a treasury there is nothing for the position holders to vote
+    /// The legacy program never managed to create the pool, so the vesting starts once it is created,
+    /// and nothing is swept as the legacy lbps have no claim deadline
+    /// The positions are counted as they are migrated, and the deposits are recorded from the vaults
+    pub fn upgrade(&self, lbp: &mut Lbp, payer: Pubkey) {
+        lbp.initialize(
+            LbpInitializeData {
+                uid: self.uid,
+                project: self.project,
+                launched_token_mint: self.launched_token_mint,
+                launched_token_lp_distribution: self.launched_token_lp_distribution,
+                launched_token_cap: self.launched_token_cap,
+                raised_token_mint: self.raised_token_mint,
+                raised_token_min_cap: self.raised_token_min_cap,
+                raised_token_max_cap: self.raised_token_max_cap,
+                raised_token_lp_distribution: 100,
+                dex: Dex::RaydiumCpSwap,
+                pool_open_time: 0,
+                lp_lock_policy: LpLockPolicy::TimeLock { duration: 0 },
+                trading_fee_split: TradingFeeSplit { project_bps: 0, treasury_bps: 0 },
+                vesting_schedule: VestingSchedule::Linear {
+                    cliff_duration: self.cliff_duration,
+                    vesting_duration: self.vesting_duration,
+                },
+                treasury_release: TreasuryRelease::Vesting { cliff_duration: 0, vesting_duration: 0 },
+                governance: GovernanceRules { voting_duration: 0, quorum_bps: 0, threshold_bps: 0 },
+                claim_deadline: u64::MAX,
+            },
+            payer,
+            self.launched_token_ata,
+            self.raised_token_ata,
+            self.bump,
+        );
+        lbp.raised_token_cap = self.raised_token_cap;
+        lbp.phase = self.phase.clone();
+    }
+}
+
+/// The layout of the positions created before the versioning
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, Eq, PartialEq)]
+pub struct LegacyPositionV0 {
+    pub mint: Pubkey,
+    pub lbp: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+impl LegacyPositionV0 {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        check_program_account(account)?;
+        Self::from_data(&account.try_borrow_data()?)
+    }
+
+    pub fn from_data(data: &[u8]) -> Result<Self> {
+        load_legacy_data(data, &Position::DISCRIMINATOR, Self::LEN)
+    }
+
+    /// Initializes the position in the current layout, no trading fee having been harvested before
+    pub fn upgrade(&self, position: &mut Position, payer: Pubkey) {
+        position.initialize(self.mint, self.lbp, payer, self.amount, self.bump);
+    }
+}
+
+fn check_program_account(account: &AccountInfo) -> Result<()> {
+    if account.owner != &crate::ID {
+        return Err(anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram.into());
+    }
+
+    Ok(())
+}
+
+/// Reads the data of an account of a legacy layout, which must have the discriminator of the account
+/// it was created as and the exact space of the legacy layout. Any other space is the one of an
+/// account already upgraded
+fn load_legacy_data<T: AnchorDeserialize>(data: &[u8], discriminator: &[u8], len: usize) -> Result<T> {
+    if data.len() < 8 || &data[..8] != discriminator {
+        return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+    if data.len() != len {
+        return err!(ErrorCode::AccountUpToDate);
+    }
+
+    T::deserialize(&mut &data[8..]).map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
+}
+
+/// Writes back an account of a legacy layout, keeping its discriminator
+fn save_legacy_account<T: AnchorSerialize>(value: &T, account: &AccountInfo) -> Result<()> {
+    let mut data = account.try_borrow_mut_data()?;
+    value.serialize(&mut &mut data[8..])?;
+
+    Ok(())
+}
@@ -1,3 +1,12 @@
 pub mod config;
 pub mod lbp;
 pub mod position;
+pub mod referral;
+pub mod proposal;
+pub mod vote;
+pub mod vesting_schedule;
+pub mod grant;
+pub mod admin_proposal;
+pub mod role;
+pub mod versioning;
+pub mod legacy;
@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::POSITION_VERSION;
+use crate::state::lbp::Lbp;
+
 #[account]
 #[derive(InitSpace)]
 pub struct Position {
@@ -7,20 +10,53 @@ pub struct Position {
     pub mint: Pubkey,
     /// The lpb this position is linked to
     pub lbp: Pubkey,
+    /// The account which paid the rent of the position, refunded once it is closed
+    pub payer: Pubkey,
     /// The amount deposited by the user
     pub amount: u64,
+    /// The launched token fees per share of the lbp at the last claim of the trading fees, in Q64.64
+    pub launched_token_fee_checkpoint_x64: u128,
+    /// The raised token fees per share of the lbp at the last claim of the trading fees, in Q64.64
+    pub raised_token_fee_checkpoint_x64: u128,
+    /// The amount of launched token already claimed by the position
+    pub launched_token_claimed: u64,
     /// The bump of the pda
     pub bump: u8,
-    // TODO: define a field to track how much has already been claimed for the position - should be dynamic and function of self.amount and lbp.user_cap
+
+    /// The layout version of the account
+    /// The accounts created before the versioning have the layout of [`LegacyPositionV0`] and no version,
+    /// they are rewritten in the current layout by the migration
+    pub version: u8,
+    /// Space reserved for the fields of the next layouts
+    pub reserved: [u8; 64],
 }
 
 impl Position {
     pub const LEN: usize = 8 + Self::INIT_SPACE;
 
-    pub fn initialize(&mut self, mint: Pubkey, lbp: Pubkey, amount: u64, bump: u8) {
+    pub fn initialize(&mut self, mint: Pubkey, lbp: Pubkey, payer: Pubkey, amount: u64, bump: u8) {
         self.mint = mint;
         self.lbp = lbp;
+        self.payer = payer;
         self.amount = amount;
+        self.launched_token_fee_checkpoint_x64 = 0;
+        self.raised_token_fee_checkpoint_x64 = 0;
+        self.launched_token_claimed = 0;
         self.bump = bump;
+        self.version = POSITION_VERSION;
+        self.reserved = [0; 64];
+    }
+
+    /// Returns the launched and raised token fees harvested for the position since its last claim,
+    /// pro rata of the amount deposited
+    pub fn pending_fees(&self, lbp: &Lbp) -> (u64, u64) {
+        let launched_token_fees = (((lbp.launched_token_fee_per_share_x64 - self.launched_token_fee_checkpoint_x64)
+            * self.amount as u128)
+            >> 64) as u64;
+        let raised_token_fees = (((lbp.raised_token_fee_per_share_x64 - self.raised_token_fee_checkpoint_x64)
+            * self.amount as u128)
+            >> 64) as u64;
+
+        (launched_token_fees, raised_token_fees)
     }
 }
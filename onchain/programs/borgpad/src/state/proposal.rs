@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+/// What a proposal does once approved by the position holders
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, Eq, PartialEq)]
+pub enum ProposalKind {
+    /// Releases the next tranche of the treasury to the project
+    ReleaseTranche,
+    /// Returns the unreleased treasury pro rata to the positions and closes out the lbp
+    EmergencyRefund,
+}
+
+/// Valid status change:
+/// Voting -> Approved
+/// Voting -> Rejected
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, Eq, PartialEq)]
+pub enum ProposalStatus {
+    Voting,
+    Approved,
+    Rejected,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    /// The lbp this proposal is linked to
+    pub lbp: Pubkey,
+    /// The index of the proposal in the lbp
+    pub index: u32,
+    /// What the proposal does once approved
+    pub kind: ProposalKind,
+    /// The amount of raised token released to the project, or refunded to the positions, if the proposal is approved
+    pub amount: u64,
+    /// The end of the voting window
+    /// Expressed as Unix time (i.e. seconds since the Unix epoch).
+    pub voting_end_time: u64,
+    /// The amount deposited by the positions approving the proposal
+    pub votes_for: u64,
+    /// The amount deposited by the positions rejecting the proposal
+    pub votes_against: u64,
+    /// The status of the proposal
+    pub status: ProposalStatus,
+    /// The bump of the pda
+    pub bump: u8,
+}
+
+impl Proposal {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    pub fn initialize(
+        &mut self,
+        lbp: Pubkey,
+        index: u32,
+        kind: ProposalKind,
+        amount: u64,
+        voting_end_time: u64,
+        bump: u8,
+    ) {
+        self.lbp = lbp;
+        self.index = index;
+        self.kind = kind;
+        self.amount = amount;
+        self.voting_end_time = voting_end_time;
+        self.votes_for = 0;
+        self.votes_against = 0;
+        self.status = ProposalStatus::Voting;
+        self.bump = bump;
+    }
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Referral {
+    /// The lbp this referral is linked to
+    pub lbp: Pubkey,
+    /// The referrer receiving the reward
+    /// Only checked to differ from the depositing key, see `user_deposit`
+    pub referrer: Pubkey,
+    /// The amount deposited by the users referred by the referrer
+    pub amount: u64,
+    /// Whether the referrer has claimed the reward
+    pub claimed: bool,
+    /// The bump of the pda
+    pub bump: u8,
+}
+
+impl Referral {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    pub fn initialize(&mut self, lbp: Pubkey, referrer: Pubkey, bump: u8) {
+        self.lbp = lbp;
+        self.referrer = referrer;
+        self.amount = 0;
+        self.claimed = false;
+        self.bump = bump;
+    }
+}
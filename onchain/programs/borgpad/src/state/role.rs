@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// The duties delegated by the admin
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    /// Creates and finalizes the lbps
+    SaleOperator,
+    /// Pauses and unpauses the sales
+    Pauser,
+    /// Sets the platform and referral fees
+    FeeManager,
+    /// Sweeps what is left unclaimed in the lbps
+    Treasury,
+}
+
+/// A role of the registry of the config
+/// Like the admin authority, a role is transferred in two steps: it is granted to a pending holder
+/// who must accept it
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, Eq, PartialEq)]
+pub struct RoleGrant {
+    pub role: Role,
+    /// The key holding the role, none until the first grant is accepted
+    pub holder: Option<Pubkey>,
+    /// The key the role has been granted to, until it accepts it
+    pub pending_holder: Option<Pubkey>,
+}
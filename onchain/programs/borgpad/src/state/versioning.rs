@@ -0,0 +1,249 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::constants::{CONFIG_VERSION, LBP_VERSION, POSITION_VERSION};
+use crate::state::config::Config;
+use crate::state::lbp::Lbp;
+use crate::state::position::Position;
+
+/// An account whose layout is versioned
+/// New fields are appended after the existing ones, or carved out of the reserved space,
+/// so that the accounts of a previous layout can be upgraded in place
+pub trait Versioned: AccountSerialize + AccountDeserialize + Owner {
+    /// The current layout version
+    const VERSION: u8;
+    /// The space of the account in the current layout, discriminator included
+    const SPACE: usize;
+}
+
+impl Versioned for Config {
+    const VERSION: u8 = CONFIG_VERSION;
+    const SPACE: usize = Config::LEN;
+}
+
+impl Versioned for Lbp {
+    const VERSION: u8 = LBP_VERSION;
+    const SPACE: usize = Lbp::LEN;
+}
+
+impl Versioned for Position {
+    const VERSION: u8 = POSITION_VERSION;
+    const SPACE: usize = Position::LEN;
+}
+
+/// Upgrades in place an account of a previous layout to the current one
+/// The account is grown to the current space, the payer funding the extra rent, and zeroed like a
+/// newly created account. The caller then initializes it from the fields of the legacy layout
+pub fn upgrade_account<'info, T: Versioned>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    initialize: impl FnOnce(&mut T) -> Result<()>,
+) -> Result<T> {
+    if account.owner != &T::owner() {
+        return Err(anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram.into());
+    }
+
+    let rent = Rent::get()?.minimum_balance(T::SPACE).saturating_sub(account.lamports());
+    if rent > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent,
+        )?;
+    }
+    account.realloc(T::SPACE, false)?;
+
+    let data = upgrade_data(&mut account.try_borrow_mut_data()?, initialize)?;
+
+    Ok(data)
+}
+
+/// Overwrites the fields of the account with the ones set by the initializer, keeping its discriminator
+fn upgrade_data<T: Versioned>(data: &mut [u8], initialize: impl FnOnce(&mut T) -> Result<()>) -> Result<T> {
+    data[8..].fill(0);
+
+    let mut upgraded = T::try_deserialize_unchecked(&mut &data[..])?;
+    initialize(&mut upgraded)?;
+    upgraded.try_serialize(&mut &mut data[..])?;
+
+    Ok(upgraded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+    use crate::errors::ErrorCode;
+    use crate::state::legacy::{LegacyConfigV0, LegacyLbpV0, LegacyPositionV0};
+    use crate::state::lbp::{Dex, Phase, PoolStatus};
+    use crate::state::vesting_schedule::VestingSchedule;
+
+    /// Returns the data of an account created with a legacy layout, padded to its space
+    fn legacy_account<T: AnchorSerialize>(discriminator: &[u8], legacy: &T, len: usize) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        data.resize(len, 0);
+        data
+    }
+
+    /// Grows the data to the current space and upgrades it as the migrations do
+    fn upgrade<T: Versioned>(mut data: Vec<u8>, initialize: impl FnOnce(&mut T)) -> T {
+        data.resize(T::SPACE, 0);
+        upgrade_data::<T>(&mut data, |account| {
+            initialize(account);
+            Ok(())
+        }).unwrap();
+        T::try_deserialize(&mut &data[..]).unwrap()
+    }
+
+    #[test]
+    fn upgrades_legacy_config() {
+        let legacy = LegacyConfigV0 {
+            admin_authority: Pubkey::new_unique(),
+            pending_admin_authority: Some(Pubkey::new_unique()),
+            whitelist_authority: Pubkey::new_unique(),
+            bump: 254,
+        };
+
+        let data = legacy_account(&Config::DISCRIMINATOR, &legacy, LegacyConfigV0::LEN);
+        let loaded = LegacyConfigV0::from_data(&data).unwrap();
+        assert_eq!(loaded, legacy);
+
+        let config: Config = upgrade(data, |config| loaded.upgrade(config));
+
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.admin_authority, legacy.admin_authority);
+        assert_eq!(config.pending_admin_authority, legacy.pending_admin_authority);
+        assert_eq!(config.whitelist_authority, legacy.whitelist_authority);
+        assert_eq!(config.bump, 254);
+        assert!(config.admin_members.is_empty());
+        assert!(config.roles.is_empty());
+        assert_eq!(config.fee_treasury, legacy.admin_authority);
+        assert_eq!(config.raised_token_fee_bps, 0);
+        assert!(config.refunds_exempt_from_pause);
+        assert_eq!(config.reserved, [0; 128]);
+    }
+
+    #[test]
+    fn upgrades_legacy_lbp() {
+        let legacy = LegacyLbpV0 {
+            uid: 42,
+            project: Pubkey::new_unique(),
+            launched_token_mint: Pubkey::new_unique(),
+            launched_token_ata: Pubkey::new_unique(),
+            launched_token_lp_distribution: 40,
+            launched_token_cap: 1_000_000,
+            raised_token_mint: Pubkey::new_unique(),
+            raised_token_ata: Pubkey::new_unique(),
+            raised_token_min_cap: 500_000,
+            raised_token_max_cap: 2_000_000,
+            raised_token_cap: 0,
+            phase: Phase::Refund,
+            vesting_start_time: 0,
+            cliff_duration: 10,
+            vesting_duration: 100,
+            bump: 253,
+        };
+
+        let data = legacy_account(&Lbp::DISCRIMINATOR, &legacy, LegacyLbpV0::LEN);
+        let loaded = LegacyLbpV0::from_data(&data).unwrap();
+        assert_eq!(loaded, legacy);
+
+        let payer = Pubkey::new_unique();
+        let lbp: Lbp = upgrade(data, |lbp| loaded.upgrade(lbp, payer));
+
+        assert_eq!(lbp.version, LBP_VERSION);
+        assert_eq!(lbp.uid, 42);
+        assert_eq!(lbp.project, legacy.project);
+        assert_eq!(lbp.payer, payer);
+        assert_eq!(lbp.launched_token_mint, legacy.launched_token_mint);
+        assert_eq!(lbp.launched_token_ata, legacy.launched_token_ata);
+        assert_eq!(lbp.launched_token_lp_distribution, 40);
+        assert_eq!(lbp.launched_token_cap, 1_000_000);
+        assert_eq!(lbp.raised_token_mint, legacy.raised_token_mint);
+        assert_eq!(lbp.raised_token_ata, legacy.raised_token_ata);
+        assert_eq!(lbp.raised_token_min_cap, 500_000);
+        assert_eq!(lbp.raised_token_max_cap, 2_000_000);
+        assert_eq!(lbp.raised_token_lp_distribution, 100);
+        assert_eq!(lbp.phase, Phase::Refund);
+        assert_eq!(lbp.pool_status, PoolStatus::PoolPending);
+        assert_eq!(lbp.dex, Dex::RaydiumCpSwap);
+        assert_eq!(lbp.vesting_start_time, u64::MAX);
+        assert_eq!(lbp.vesting_schedule, VestingSchedule::Linear { cliff_duration: 10, vesting_duration: 100 });
+        assert_eq!(lbp.claim_deadline, u64::MAX);
+        assert_eq!(lbp.position_count, 0);
+        assert_eq!(lbp.vault_count, 2);
+        assert_eq!(lbp.bump, 253);
+        assert_eq!(lbp.reserved, [0; 256]);
+    }
+
+    #[test]
+    fn upgrades_legacy_position() {
+        let legacy = LegacyPositionV0 {
+            mint: Pubkey::new_unique(),
+            lbp: Pubkey::new_unique(),
+            amount: 500,
+            bump: 252,
+        };
+
+        let data = legacy_account(&Position::DISCRIMINATOR, &legacy, LegacyPositionV0::LEN);
+        let loaded = LegacyPositionV0::from_data(&data).unwrap();
+        assert_eq!(loaded, legacy);
+
+        let payer = Pubkey::new_unique();
+        let position: Position = upgrade(data, |position| loaded.upgrade(position, payer));
+
+        assert_eq!(position.version, POSITION_VERSION);
+        assert_eq!(position.mint, legacy.mint);
+        assert_eq!(position.lbp, legacy.lbp);
+        assert_eq!(position.payer, payer);
+        assert_eq!(position.amount, 500);
+        assert_eq!(position.raised_token_fee_checkpoint_x64, 0);
+        assert_eq!(position.bump, 252);
+    }
+
+    #[test]
+    fn rejects_upgraded_and_foreign_accounts() {
+        let legacy = LegacyPositionV0 {
+            mint: Pubkey::new_unique(),
+            lbp: Pubkey::new_unique(),
+            amount: 500,
+            bump: 252,
+        };
+
+        // An account of the current layout is already upgraded
+        let data = legacy_account(&Position::DISCRIMINATOR, &legacy, LegacyPositionV0::LEN);
+        let position: Position = upgrade(data, |position| legacy.upgrade(position, Pubkey::new_unique()));
+        let mut data = Vec::new();
+        position.try_serialize(&mut data).unwrap();
+        data.resize(Position::LEN, 0);
+        assert_eq!(LegacyPositionV0::from_data(&data).unwrap_err(), ErrorCode::AccountUpToDate.into());
+
+        // An account of another type has another discriminator
+        let data = legacy_account(&Lbp::DISCRIMINATOR, &legacy, LegacyPositionV0::LEN);
+        assert_eq!(
+            LegacyPositionV0::from_data(&data).unwrap_err(),
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into()
+        );
+    }
+
+    #[test]
+    fn current_accounts_fit_their_space() {
+        let mut data = vec![0u8; Config::LEN];
+        data[..8].copy_from_slice(&Config::DISCRIMINATOR);
+        let mut config: Config = Config::try_deserialize_unchecked(&mut &data[..]).unwrap();
+        config.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 254);
+        let mut data = Vec::new();
+        config.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= Config::LEN);
+
+        let loaded = Config::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(loaded.version, CONFIG_VERSION);
+    }
+}
@@ -0,0 +1,205 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{BPS_DENOMINATOR, MAX_UNLOCK_POINTS, UNLOCKED_FRACTION_DENOMINATOR};
+use crate::errors::ErrorCode;
+use crate::utils::mul_div;
+
+/// A point of a custom vesting schedule
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UnlockPoint {
+    /// The time elapsed since the start of the vesting
+    /// Expressed as Unix time (i.e. seconds since the Unix epoch).
+    pub offset: u64,
+    /// The part of the amount unlocked from this point, cumulative, in basis points
+    pub bps: u16,
+}
+
+/// How an amount is unlocked over time from the start of its vesting
+/// Durations are expressed as Unix time (i.e. seconds since the Unix epoch).
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Debug, Eq, PartialEq)]
+pub enum VestingSchedule {
+    /// Unlocked linearly over `vesting_duration` after a cliff
+    Linear { cliff_duration: u64, vesting_duration: u64 },
+    /// Unlocked by `step_count` equal steps, the n-th step being unlocked `n * step_duration` after a cliff
+    Stepwise { cliff_duration: u64, step_duration: u64, step_count: u16 },
+    /// A part unlocked at the start, then the rest unlocked linearly over `vesting_duration` after a cliff
+    TgeThenLinear { tge_bps: u16, cliff_duration: u64, vesting_duration: u64 },
+    /// Unlocked by the steps of a table sorted by offset and ending at the whole amount
+    Custom {
+        #[max_len(MAX_UNLOCK_POINTS)]
+        points: Vec<UnlockPoint>,
+    },
+}
+
+impl VestingSchedule {
+    /// Checks the schedule eventually unlocks the whole amount
+    pub fn validate(&self) -> Result<()> {
+        let valid = match self {
+            VestingSchedule::Linear { .. } => true,
+            VestingSchedule::Stepwise { step_duration, step_count, .. } => *step_duration > 0 && *step_count > 0,
+            VestingSchedule::TgeThenLinear { tge_bps, .. } => *tge_bps as u64 <= BPS_DENOMINATOR,
+            VestingSchedule::Custom { points } => {
+                !points.is_empty()
+                    && points.len() <= MAX_UNLOCK_POINTS as usize
+                    && points.windows(2).all(|pair| pair[0].offset < pair[1].offset && pair[0].bps <= pair[1].bps)
+                    && points.last().is_some_and(|point| point.bps as u64 == BPS_DENOMINATOR)
+            }
+        };
+
+        if !valid {
+            return err!(ErrorCode::InvalidVestingSchedule);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the fraction unlocked at `now` for a vesting starting at `start_time`,
+    /// expressed over `UNLOCKED_FRACTION_DENOMINATOR`
+    pub fn unlocked_fraction(&self, start_time: u64, now: u64) -> u64 {
+        if now < start_time {
+            return 0;
+        }
+        let elapsed = now - start_time;
+
+        match self {
+            VestingSchedule::Linear { cliff_duration, vesting_duration } => {
+                linear_fraction(elapsed, *cliff_duration, *vesting_duration)
+            }
+            VestingSchedule::Stepwise { cliff_duration, step_duration, step_count } => {
+                if elapsed < *cliff_duration || *step_count == 0 {
+                    return 0;
+                }
+                let steps = ((elapsed - cliff_duration) / (*step_duration).max(1)).min(*step_count as u64);
+                mul_div(UNLOCKED_FRACTION_DENOMINATOR, steps, *step_count as u64)
+            }
+            VestingSchedule::TgeThenLinear { tge_bps, cliff_duration, vesting_duration } => {
+                let tge_fraction = mul_div(UNLOCKED_FRACTION_DENOMINATOR, *tge_bps as u64, BPS_DENOMINATOR);
+                tge_fraction
+                    + mul_div(
+                        UNLOCKED_FRACTION_DENOMINATOR - tge_fraction,
+                        linear_fraction(elapsed, *cliff_duration, *vesting_duration),
+                        UNLOCKED_FRACTION_DENOMINATOR,
+                    )
+            }
+            VestingSchedule::Custom { points } => points
+                .iter()
+                .take_while(|point| point.offset <= elapsed)
+                .last()
+                .map_or(0, |point| mul_div(UNLOCKED_FRACTION_DENOMINATOR, point.bps as u64, BPS_DENOMINATOR)),
+        }
+    }
+
+    /// Returns the part of `amount` unlocked at `now` for a vesting starting at `start_time`
+    pub fn unlocked_amount(&self, amount: u64, start_time: u64, now: u64) -> u64 {
+        mul_div(amount, self.unlocked_fraction(start_time, now), UNLOCKED_FRACTION_DENOMINATOR)
+    }
+}
+
+fn linear_fraction(elapsed: u64, cliff_duration: u64, vesting_duration: u64) -> u64 {
+    if elapsed < cliff_duration {
+        0
+    } else if elapsed - cliff_duration >= vesting_duration {
+        UNLOCKED_FRACTION_DENOMINATOR
+    } else {
+        mul_div(UNLOCKED_FRACTION_DENOMINATOR, elapsed - cliff_duration, vesting_duration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const START: u64 = 1_000;
+    const FULL: u64 = UNLOCKED_FRACTION_DENOMINATOR;
+
+    #[test]
+    fn nothing_is_unlocked_before_the_start() {
+        let schedule = VestingSchedule::TgeThenLinear { tge_bps: 5_000, cliff_duration: 0, vesting_duration: 0 };
+        assert_eq!(schedule.unlocked_fraction(START, START - 1), 0);
+        assert_eq!(schedule.unlocked_fraction(u64::MAX, START), 0);
+    }
+
+    #[test]
+    fn linear_unlocks_after_the_cliff() {
+        let schedule = VestingSchedule::Linear { cliff_duration: 100, vesting_duration: 200 };
+        assert_eq!(schedule.unlocked_fraction(START, START + 99), 0);
+        assert_eq!(schedule.unlocked_fraction(START, START + 100), 0);
+        assert_eq!(schedule.unlocked_fraction(START, START + 150), FULL / 4);
+        assert_eq!(schedule.unlocked_fraction(START, START + 200), FULL / 2);
+        assert_eq!(schedule.unlocked_fraction(START, START + 300), FULL);
+        assert_eq!(schedule.unlocked_fraction(START, u64::MAX), FULL);
+    }
+
+    #[test]
+    fn linear_without_duration_unlocks_at_the_cliff() {
+        let schedule = VestingSchedule::Linear { cliff_duration: 100, vesting_duration: 0 };
+        assert_eq!(schedule.unlocked_fraction(START, START + 99), 0);
+        assert_eq!(schedule.unlocked_fraction(START, START + 100), FULL);
+    }
+
+    #[test]
+    fn stepwise_unlocks_by_steps() {
+        let schedule = VestingSchedule::Stepwise { cliff_duration: 100, step_duration: 30, step_count: 4 };
+        assert_eq!(schedule.unlocked_fraction(START, START + 129), 0);
+        assert_eq!(schedule.unlocked_fraction(START, START + 130), FULL / 4);
+        assert_eq!(schedule.unlocked_fraction(START, START + 189), FULL / 2);
+        assert_eq!(schedule.unlocked_fraction(START, START + 220), FULL);
+        assert_eq!(schedule.unlocked_fraction(START, START + 10_000), FULL);
+    }
+
+    #[test]
+    fn tge_then_linear_unlocks_the_tge_at_the_start() {
+        let schedule = VestingSchedule::TgeThenLinear { tge_bps: 2_000, cliff_duration: 100, vesting_duration: 100 };
+        assert_eq!(schedule.unlocked_fraction(START, START), FULL / 5);
+        assert_eq!(schedule.unlocked_fraction(START, START + 100), FULL / 5);
+        assert_eq!(schedule.unlocked_fraction(START, START + 150), FULL / 5 + FULL * 4 / 10);
+        assert_eq!(schedule.unlocked_fraction(START, START + 200), FULL);
+    }
+
+    #[test]
+    fn custom_unlocks_by_the_points_of_the_table() {
+        let schedule = VestingSchedule::Custom {
+            points: vec![
+                UnlockPoint { offset: 0, bps: 1_000 },
+                UnlockPoint { offset: 100, bps: 4_000 },
+                UnlockPoint { offset: 300, bps: 10_000 },
+            ],
+        };
+        assert_eq!(schedule.unlocked_fraction(START, START), FULL / 10);
+        assert_eq!(schedule.unlocked_fraction(START, START + 99), FULL / 10);
+        assert_eq!(schedule.unlocked_fraction(START, START + 100), FULL * 4 / 10);
+        assert_eq!(schedule.unlocked_fraction(START, START + 300), FULL);
+    }
+
+    #[test]
+    fn unlocked_amount_rounds_down() {
+        let schedule = VestingSchedule::Linear { cliff_duration: 0, vesting_duration: 3 };
+        assert_eq!(schedule.unlocked_amount(10, START, START + 1), 3);
+        assert_eq!(schedule.unlocked_amount(10, START, START + 3), 10);
+        assert_eq!(schedule.unlocked_amount(u64::MAX, START, START + 3), u64::MAX);
+    }
+
+    #[test]
+    fn validation_requires_the_whole_amount_to_unlock() {
+        assert!(VestingSchedule::Linear { cliff_duration: 0, vesting_duration: 0 }.validate().is_ok());
+        assert!(VestingSchedule::Stepwise { cliff_duration: 0, step_duration: 0, step_count: 1 }.validate().is_err());
+        assert!(VestingSchedule::Stepwise { cliff_duration: 0, step_duration: 1, step_count: 0 }.validate().is_err());
+        assert!(VestingSchedule::TgeThenLinear { tge_bps: 10_001, cliff_duration: 0, vesting_duration: 0 }
+            .validate()
+            .is_err());
+        assert!(VestingSchedule::Custom { points: vec![] }.validate().is_err());
+        assert!(VestingSchedule::Custom { points: vec![UnlockPoint { offset: 0, bps: 9_999 }] }
+            .validate()
+            .is_err());
+        assert!(VestingSchedule::Custom {
+            points: vec![UnlockPoint { offset: 10, bps: 5_000 }, UnlockPoint { offset: 10, bps: 10_000 }]
+        }
+        .validate()
+        .is_err());
+        assert!(VestingSchedule::Custom {
+            points: vec![UnlockPoint { offset: 0, bps: 5_000 }, UnlockPoint { offset: 10, bps: 10_000 }]
+        }
+        .validate()
+        .is_ok());
+    }
+}
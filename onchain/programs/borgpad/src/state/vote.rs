@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+/// The vote of a position on a proposal, which prevents the position from voting twice
+#[account]
+#[derive(InitSpace)]
+pub struct Vote {
+    /// The proposal voted
+    pub proposal: Pubkey,
+    /// The position voting
+    pub position: Pubkey,
+    /// Whether the position approves the proposal
+    pub approve: bool,
+    /// The weight of the vote, i.e., the amount deposited by the position
+    pub weight: u64,
+    /// The bump of the pda
+    pub bump: u8,
+}
+
+impl Vote {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    pub fn initialize(&mut self, proposal: Pubkey, position: Pubkey, approve: bool, weight: u64, bump: u8) {
+        self.proposal = proposal;
+        self.position = position;
+        self.approve = approve;
+        self.weight = weight;
+        self.bump = bump;
+    }
+}
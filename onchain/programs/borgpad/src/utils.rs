@@ -1,6 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::BPS_DENOMINATOR;
+
 pub fn sighash(namespace: &str, name: &str) -> [u8; 8] {
     let preimage = format!("{}:{}", namespace, name);
     let mut sighash = [0u8; 8];
     sighash.copy_from_slice(&anchor_lang::solana_program::hash::hash(preimage.as_bytes()).to_bytes()[..8]);
     sighash
 }
+
+/// Returns the part of `amount` corresponding to `bps` basis points, rounded down
+pub fn bps_of(amount: u64, bps: u16) -> u64 {
+    (amount as u128 * bps as u128 / BPS_DENOMINATOR as u128) as u64
+}
+
+/// Returns `amount * numerator / denominator` rounded down, or 0 if the denominator is 0
+pub fn mul_div(amount: u64, numerator: u64, denominator: u64) -> u64 {
+    (amount as u128 * numerator as u128)
+        .checked_div(denominator as u128)
+        .unwrap_or(0) as u64
+}
+
+/// Returns the hash of the borsh serialized `args`, identifying the arguments of an admin action
+pub fn args_hash<T: AnchorSerialize>(args: &T) -> Result<[u8; 32]> {
+    let mut data = Vec::new();
+    args.serialize(&mut data)?;
+    Ok(anchor_lang::solana_program::hash::hash(&data).to_bytes())
+}
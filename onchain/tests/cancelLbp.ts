@@ -0,0 +1,58 @@
+import { Context } from "./helpers/context"
+import * as assert from "assert";
+
+describe("Cancel lbp", () => {
+    let ctx: Context
+
+    before('Init context', async function () {
+        ctx = new Context()
+        await ctx.init()
+    })
+
+    it("Anyone else than the admin cannot cancel a sale", async () => {
+        await assert.rejects(
+            ctx.program.methods
+                .cancelLbp({fraud: {}})
+                .accountsPartial({
+                    adminAuthority: ctx.user.publicKey,
+                    lbp: ctx.fundCollectionToCancelledLbp,
+                })
+                .signers([ctx.user])
+                .rpc()
+        )
+    });
+
+    it("The admin can cancel a sale during the fund collection", async () => {
+        let lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToCancelledLbp);
+
+        assert.deepEqual(lbp.phase, {fundCollection: {}})
+        assert.deepEqual(lbp.cancelReason, null)
+
+        await ctx.program.methods
+            .cancelLbp({fraud: {}})
+            .accountsPartial({
+                adminAuthority: ctx.adminAuthority.publicKey,
+                lbp: ctx.fundCollectionToCancelledLbp,
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToCancelledLbp);
+
+        assert.deepEqual(lbp.phase, {refund: {}})
+        assert.deepEqual(lbp.cancelReason, {fraud: {}})
+    });
+
+    it("A cancelled sale cannot be cancelled again", async () => {
+        await assert.rejects(
+            ctx.program.methods
+                .cancelLbp({other: {}})
+                .accountsPartial({
+                    adminAuthority: ctx.adminAuthority.publicKey,
+                    lbp: ctx.fundCollectionToCancelledLbp,
+                })
+                .signers([ctx.adminAuthority])
+                .rpc()
+        )
+    });
+});
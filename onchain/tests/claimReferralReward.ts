@@ -0,0 +1,138 @@
+import { Context } from "./helpers/context"
+import * as assert from "assert";
+import {TOKEN_PROGRAM_ID} from "@coral-xyz/anchor/dist/cjs/utils/token";
+import {getAccount, getAssociatedTokenAddressSync, transfer} from "@solana/spl-token";
+
+describe("Claim referral reward", () => {
+    let ctx: Context
+
+    before('Init context', async function () {
+        ctx = new Context()
+        await ctx.init()
+
+        await ctx.program.methods
+            .setPlatformFees(1_000, 500, ctx.adminAuthority.publicKey)
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        await ctx.program.methods
+            .setReferralFees(5_000, 5_000)
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+    })
+
+    after('Set old fees back', async function () {
+        await ctx.program.methods
+            .setPlatformFees(0, 0, ctx.adminAuthority.publicKey)
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        await ctx.program.methods
+            .setReferralFees(0, 0)
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+    })
+
+    it("The referral rewards are kept in the lbp when the sale is finalized", async () => {
+        let lbp = await ctx.program.account.lbp.fetchNullable(ctx.referredLbp);
+        assert.deepEqual(lbp.referredTokenCap.toNumber(), ctx.raisedTokenMinCap.toNumber())
+
+        // A donation to the vault does not inflate the fees
+        await transfer(
+            ctx.connection,
+            ctx.user,
+            getAssociatedTokenAddressSync(lbp.raisedTokenMint, ctx.user.publicKey),
+            lbp.raisedTokenAta,
+            ctx.user,
+            10_000
+        )
+
+        await ctx.program.methods
+            .finalizeSale()
+            .accountsPartial({
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null,
+                lbp: ctx.referredLbp,
+                // @ts-ignore
+                raisedTokenMint: lbp.raisedTokenMint,
+                launchedTokenMint: lbp.launchedTokenMint,
+                feeTreasury: ctx.adminAuthority.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        lbp = await ctx.program.account.lbp.fetchNullable(ctx.referredLbp);
+
+        // 10% of the 500_000 raised tokens and 5% of the 1_000_000 launched tokens are taken as fees,
+        // half of which rewards the referrers as all the deposits were referred
+        assert.deepEqual(lbp.raisedTokenFee.toNumber(), 50_000)
+        assert.deepEqual(lbp.launchedTokenFee.toNumber(), 50_000)
+        assert.deepEqual(lbp.raisedTokenReferralReward.toNumber(), 25_000)
+        assert.deepEqual(lbp.launchedTokenReferralReward.toNumber(), 25_000)
+
+        const feeTreasuryRaisedToken = await getAccount(
+            ctx.connection,
+            getAssociatedTokenAddressSync(lbp.raisedTokenMint, ctx.adminAuthority.publicKey)
+        )
+        assert.ok(Number(feeTreasuryRaisedToken.amount) >= 25_000)
+    });
+
+    it("The referrer can claim its reward once", async () => {
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.referredLbp);
+        const referral = ctx.referral(ctx.referredLbp, ctx.referrer.publicKey)
+
+        const claimReferralReward = () => ctx.program.methods
+            .claimReferralReward()
+            .accountsPartial({
+                referrer: ctx.referrer.publicKey,
+                lbp: ctx.referredLbp,
+                referral: referral,
+                // @ts-ignore
+                launchedTokenMint: lbp.launchedTokenMint,
+                raisedTokenMint: lbp.raisedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.referrer])
+            .rpc()
+
+        await claimReferralReward()
+
+        const referralData = await ctx.program.account.referral.fetchNullable(referral);
+        assert.deepEqual(referralData.claimed, true)
+
+        const referrerRaisedToken = await getAccount(
+            ctx.connection,
+            getAssociatedTokenAddressSync(lbp.raisedTokenMint, ctx.referrer.publicKey)
+        )
+        assert.deepEqual(Number(referrerRaisedToken.amount), 25_000)
+
+        const referrerLaunchedToken = await getAccount(
+            ctx.connection,
+            getAssociatedTokenAddressSync(lbp.launchedTokenMint, ctx.referrer.publicKey)
+        )
+        assert.deepEqual(Number(referrerLaunchedToken.amount), 25_000)
+
+        await assert.rejects(claimReferralReward())
+    });
+});
@@ -0,0 +1,79 @@
+import { Context } from "./helpers/context"
+import * as assert from "assert";
+import {TOKEN_PROGRAM_ID} from "@coral-xyz/anchor/dist/cjs/utils/token";
+
+describe("Close lbp", () => {
+    let ctx: Context
+
+    before('Init context', async function () {
+        ctx = new Context()
+        await ctx.init()
+    })
+
+    it("The lbp cannot be closed while its vaults are open", async () => {
+        await assert.rejects(
+            ctx.program.methods
+                .closeLbp()
+                .accountsPartial({
+                    payer: ctx.adminAuthority.publicKey,
+                    lbp: ctx.fundCollectionToCancelledLbp,
+                })
+                .rpc()
+        )
+    });
+
+    it("Only the admin or the payer of the lbp can close its vaults", async () => {
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToCancelledLbp);
+
+        await assert.rejects(
+            ctx.program.methods
+                .closeLbpVault()
+                .accountsPartial({
+                    authority: ctx.user.publicKey,
+                    adminProposal: null,
+                    payer: lbp.payer,
+                    lbp: ctx.fundCollectionToCancelledLbp,
+                    mint: lbp.launchedTokenMint,
+                    tokenProgram: TOKEN_PROGRAM_ID
+                })
+                .signers([ctx.user])
+                .rpc()
+        )
+    });
+
+    it("The admin can close the empty vaults and the lbp of a cancelled sale", async () => {
+        let lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToCancelledLbp);
+
+        assert.deepEqual(lbp.phase, {refund: {}})
+        assert.deepEqual(lbp.positionCount, 0)
+
+        for (const mint of [lbp.launchedTokenMint, lbp.raisedTokenMint]) {
+            await ctx.program.methods
+                .closeLbpVault()
+                .accountsPartial({
+                    authority: ctx.adminAuthority.publicKey,
+                    adminProposal: null,
+                    payer: lbp.payer,
+                    lbp: ctx.fundCollectionToCancelledLbp,
+                    mint: mint,
+                    tokenProgram: TOKEN_PROGRAM_ID
+                })
+                .signers([ctx.adminAuthority])
+                .rpc()
+        }
+
+        lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToCancelledLbp);
+        assert.deepEqual(lbp.vaultCount, 0)
+
+        await ctx.program.methods
+            .closeLbp()
+            .accountsPartial({
+                payer: lbp.payer,
+                lbp: ctx.fundCollectionToCancelledLbp,
+            })
+            .rpc()
+
+        lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToCancelledLbp);
+        assert.equal(lbp, null)
+    });
+});
@@ -0,0 +1,649 @@
+import { Context } from "./helpers/context"
+import * as assert from "assert";
+import {BN} from "@coral-xyz/anchor";
+import {TOKEN_PROGRAM_ID} from "@coral-xyz/anchor/dist/cjs/utils/token";
+import * as anchor from "@coral-xyz/anchor";
+import {TOKEN_2022_PROGRAM_ID, createMint, getAccount, getAssociatedTokenAddressSync, getOrCreateAssociatedTokenAccount, mintTo, transfer} from "@solana/spl-token";
+import {Keypair, PublicKey, Transaction, TransactionInstruction} from "@solana/web3.js";
+import {sha256} from "js-sha256";
+
+const MEMO_PROGRAM_ID = new PublicKey("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr")
+
+describe("Finalize sale", () => {
+    let ctx: Context
+    let poolStateAddress: PublicKey
+    let lpMintAddress: PublicKey
+    let creatorLpTokenAddress: PublicKey
+    let launchedTokenVaultAddress: PublicKey
+    let raisedTokenVaultAddress: PublicKey
+    let observationStateAddress: PublicKey
+
+    before('Init context', async function () {
+        ctx = new Context()
+        await ctx.init()
+
+        let lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+
+        const [token0Mint, token1Mint] = Buffer.compare(lbp.raisedTokenMint.toBuffer(), lbp.launchedTokenMint.toBuffer()) < 0
+            ? [lbp.raisedTokenMint, lbp.launchedTokenMint]
+            : [lbp.launchedTokenMint, lbp.raisedTokenMint]
+
+        poolStateAddress = PublicKey.findProgramAddressSync(
+            [
+                anchor.utils.bytes.utf8.encode("pool"),
+                ctx.raydiumCpmmAmmConfig.toBuffer(),
+                token0Mint.toBuffer(),
+                token1Mint.toBuffer()
+            ],
+            ctx.raydiumCpmmProgramId
+        )[0]
+
+        lpMintAddress = PublicKey.findProgramAddressSync(
+            [
+                anchor.utils.bytes.utf8.encode("pool_lp_mint"),
+                poolStateAddress.toBuffer(),
+            ],
+            ctx.raydiumCpmmProgramId
+        )[0]
+
+        creatorLpTokenAddress = getAssociatedTokenAddressSync(
+            lpMintAddress,
+            ctx.vaultAuthority(ctx.fundCollectionToVestingPhaseLbp),
+            true,
+        )
+
+        launchedTokenVaultAddress = PublicKey.findProgramAddressSync(
+            [
+                anchor.utils.bytes.utf8.encode("pool_vault"),
+                poolStateAddress.toBuffer(),
+                lbp.launchedTokenMint.toBuffer(),
+            ],
+            ctx.raydiumCpmmProgramId
+        )[0]
+
+        raisedTokenVaultAddress = PublicKey.findProgramAddressSync(
+            [
+                anchor.utils.bytes.utf8.encode("pool_vault"),
+                poolStateAddress.toBuffer(),
+                lbp.raisedTokenMint.toBuffer(),
+            ],
+            ctx.raydiumCpmmProgramId
+        )[0]
+
+        observationStateAddress = PublicKey.findProgramAddressSync(
+            [
+                anchor.utils.bytes.utf8.encode("observation"),
+                poolStateAddress.toBuffer(),
+            ],
+            ctx.raydiumCpmmProgramId
+        )[0]
+    })
+
+    function createPool(lbp, remainingAccounts) {
+        return ctx.program.methods
+            .createPool()
+            .accountsPartial({
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null,
+                lbp: ctx.fundCollectionToVestingPhaseLbp,
+                // @ts-ignore
+                raisedTokenMint: lbp.raisedTokenMint,
+                launchedTokenMint: lbp.launchedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .remainingAccounts(remainingAccounts)
+            .signers([ctx.adminAuthority])
+            .rpc()
+    }
+
+    function poolAccounts() {
+        return [
+            {pubkey: ctx.raydiumCpmmProgramId, isSigner: false, isWritable: false},
+            {pubkey: ctx.raydiumCpmmAmmConfig, isSigner: false, isWritable: false},
+            {pubkey: ctx.raydiumCpmmAuthority, isSigner: false, isWritable: false},
+            {pubkey: poolStateAddress, isSigner: false, isWritable: true},
+            {pubkey: lpMintAddress, isSigner: false, isWritable: true},
+            {pubkey: creatorLpTokenAddress, isSigner: false, isWritable: true},
+            {pubkey: launchedTokenVaultAddress, isSigner: false, isWritable: true},
+            {pubkey: raisedTokenVaultAddress, isSigner: false, isWritable: true},
+            {pubkey: ctx.raydiumCpmmAmmCreatePoolFeeReceiver, isSigner: false, isWritable: true},
+            {pubkey: observationStateAddress, isSigner: false, isWritable: true},
+        ]
+    }
+
+    function harvestFees(lbp) {
+        const [token0Vault, token1Vault] = Buffer.compare(lbp.raisedTokenMint.toBuffer(), lbp.launchedTokenMint.toBuffer()) < 0
+            ? [raisedTokenVaultAddress, launchedTokenVaultAddress]
+            : [launchedTokenVaultAddress, raisedTokenVaultAddress]
+
+        return ctx.program.methods
+            .harvestFees()
+            .accountsPartial({
+                payer: ctx.user.publicKey,
+                lbp: ctx.fundCollectionToVestingPhaseLbp,
+                project: ctx.project.publicKey,
+                feeTreasury: ctx.adminAuthority.publicKey,
+                // @ts-ignore
+                raisedTokenMint: lbp.raisedTokenMint,
+                launchedTokenMint: lbp.launchedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .remainingAccounts([
+                {pubkey: ctx.raydiumCpmmProgramId, isSigner: false, isWritable: false},
+                {pubkey: ctx.raydiumCpmmAuthority, isSigner: false, isWritable: false},
+                {pubkey: poolStateAddress, isSigner: false, isWritable: true},
+                {pubkey: creatorLpTokenAddress, isSigner: false, isWritable: true},
+                {pubkey: token0Vault, isSigner: false, isWritable: true},
+                {pubkey: token1Vault, isSigner: false, isWritable: true},
+                {pubkey: lpMintAddress, isSigner: false, isWritable: true},
+                {pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false},
+                {pubkey: MEMO_PROGRAM_ID, isSigner: false, isWritable: false},
+            ])
+            .signers([ctx.user])
+            .rpc()
+    }
+
+    // Sells `amount` raised tokens of the user on the pool with the swap_base_input instruction of Raydium CP-Swap
+    async function swapRaisedToken(lbp, amount: number) {
+        const userLaunchedTokenAta = await getOrCreateAssociatedTokenAccount(ctx.connection, ctx.user, lbp.launchedTokenMint, ctx.user.publicKey)
+
+        const data = Buffer.alloc(24)
+        Buffer.from(sha256.digest("global:swap_base_input")).copy(data, 0, 0, 8)
+        data.writeBigUInt64LE(BigInt(amount), 8)
+        data.writeBigUInt64LE(BigInt(0), 16)
+
+        const swapBaseInput = new TransactionInstruction({
+            programId: ctx.raydiumCpmmProgramId,
+            keys: [
+                {pubkey: ctx.user.publicKey, isSigner: true, isWritable: false},
+                {pubkey: ctx.raydiumCpmmAuthority, isSigner: false, isWritable: false},
+                {pubkey: ctx.raydiumCpmmAmmConfig, isSigner: false, isWritable: false},
+                {pubkey: poolStateAddress, isSigner: false, isWritable: true},
+                {pubkey: getAssociatedTokenAddressSync(lbp.raisedTokenMint, ctx.user.publicKey), isSigner: false, isWritable: true},
+                {pubkey: userLaunchedTokenAta.address, isSigner: false, isWritable: true},
+                {pubkey: raisedTokenVaultAddress, isSigner: false, isWritable: true},
+                {pubkey: launchedTokenVaultAddress, isSigner: false, isWritable: true},
+                {pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false},
+                {pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false},
+                {pubkey: lbp.raisedTokenMint, isSigner: false, isWritable: false},
+                {pubkey: lbp.launchedTokenMint, isSigner: false, isWritable: false},
+                {pubkey: observationStateAddress, isSigner: false, isWritable: true},
+            ],
+            data,
+        })
+
+        await ctx.provider.sendAndConfirm(new Transaction().add(swapBaseInput), [ctx.user])
+    }
+
+    it("It can finalize the sale", async () => {
+        let lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+
+        assert.deepEqual(lbp.phase, {fundCollection: {}})
+
+        // Tokens sent directly to the vaults neither block the finalisation nor count as deposits
+        await transfer(
+            ctx.connection,
+            ctx.project,
+            getAssociatedTokenAddressSync(lbp.launchedTokenMint, ctx.project.publicKey),
+            lbp.launchedTokenAta,
+            ctx.project,
+            1
+        )
+        await transfer(
+            ctx.connection,
+            ctx.user,
+            getAssociatedTokenAddressSync(lbp.raisedTokenMint, ctx.user.publicKey),
+            lbp.raisedTokenAta,
+            ctx.user,
+            1
+        )
+
+        await ctx.program.methods
+            .finalizeSale()
+            .accountsPartial({
+                adminAuthority: ctx.adminAuthority.publicKey,
+                lbp: ctx.fundCollectionToVestingPhaseLbp,
+                // @ts-ignore
+                raisedTokenMint: lbp.raisedTokenMint,
+                launchedTokenMint: lbp.launchedTokenMint,
+                feeTreasury: ctx.adminAuthority.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+
+        assert.deepEqual(lbp.phase, {vesting: {}})
+        assert.deepEqual(lbp.poolStatus, {poolPending: {}})
+        assert.equal(lbp.raisedTokenCap.toNumber(), lbp.raisedTokenDeposited.toNumber())
+        assert.equal(lbp.raisedTokenCap.toNumber(), ctx.raisedTokenMinCap.toNumber())
+        // The launched tokens not used for the liquidity are kept for the positions
+        assert.equal(
+            lbp.launchedTokenReward.toNumber(),
+            Math.floor((lbp.launchedTokenDeposited.toNumber() - lbp.launchedTokenFee.toNumber()) * (100 - lbp.launchedTokenLpDistribution) / 100)
+        )
+    });
+
+    it("A failed pool creation leaves the pool pending so that it can be retried", async () => {
+        let lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+
+        // The observation state of the pool is missing
+        await assert.rejects(createPool(lbp, poolAccounts().slice(0, -1)))
+
+        lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+        assert.deepEqual(lbp.poolStatus, {poolPending: {}})
+        assert.equal(lbp.vestingStartTime.toString(), "18446744073709551615")
+    });
+
+    it("It can create the pool once the sale is finalized", async () => {
+        let lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+
+        await createPool(lbp, poolAccounts())
+
+        lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+
+        assert.deepEqual(lbp.poolStatus, {poolCreated: {}})
+        assert.notEqual(lbp.vestingStartTime.toString(), "18446744073709551615")
+    });
+
+    it("Creating the pool again is a no-op", async () => {
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+
+        await createPool(lbp, poolAccounts())
+
+        const recreatedLbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+        assert.deepEqual(recreatedLbp.poolStatus, {poolCreated: {}})
+        assert.deepEqual(recreatedLbp.vestingStartTime.toString(), lbp.vestingStartTime.toString())
+        assert.deepEqual(recreatedLbp.lpTokenAmount.toNumber(), lbp.lpTokenAmount.toNumber())
+    });
+
+    it("The payer can reclaim the pool funding left to the vault authority", async () => {
+        const vaultAuthority = ctx.vaultAuthority(ctx.fundCollectionToVestingPhaseLbp)
+        assert.notEqual(await ctx.connection.getBalance(vaultAuthority), 0)
+
+        await ctx.program.methods
+            .reclaimPoolFunding()
+            .accountsPartial({
+                authority: ctx.adminAuthority.publicKey,
+                adminProposal: null,
+                payer: ctx.adminAuthority.publicKey,
+                lbp: ctx.fundCollectionToVestingPhaseLbp,
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        assert.equal(await ctx.connection.getBalance(vaultAuthority), 0)
+    });
+
+    it("Anyone can harvest the trading fees of the pool", async () => {
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+
+        await harvestFees(lbp)
+
+        // Without any trade, no lp token is withdrawn
+        const harvestedLbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+        assert.deepEqual(harvestedLbp.lpTokenAmount.toNumber(), lbp.lpTokenAmount.toNumber())
+        assert.deepEqual(harvestedLbp.raisedTokenHolderFees.toNumber(), 0)
+    });
+
+    it("The trading fees are harvested for the open positions", async () => {
+        await swapRaisedToken(await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp), 200_000)
+
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+        const launchedTokenBalBefore = (await getAccount(ctx.connection, lbp.launchedTokenAta)).amount
+        const raisedTokenBalBefore = (await getAccount(ctx.connection, lbp.raisedTokenAta)).amount
+
+        await harvestFees(lbp)
+
+        const harvestedLbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+        assert.ok(harvestedLbp.lpTokenAmount.lt(lbp.lpTokenAmount))
+
+        // The split of the lbp leaves all the fees to the holders, shared among the deposits of the open positions
+        const launchedTokenFees = (await getAccount(ctx.connection, lbp.launchedTokenAta)).amount - launchedTokenBalBefore
+        const raisedTokenFees = (await getAccount(ctx.connection, lbp.raisedTokenAta)).amount - raisedTokenBalBefore
+        assert.notEqual(raisedTokenFees, BigInt(0))
+
+        assert.deepEqual(harvestedLbp.launchedTokenHolderFees.toString(), launchedTokenFees.toString())
+        assert.deepEqual(harvestedLbp.raisedTokenHolderFees.toString(), raisedTokenFees.toString())
+        assert.deepEqual(
+            harvestedLbp.raisedTokenFeePerShareX64.toString(),
+            ((raisedTokenFees << BigInt(64)) / BigInt(lbp.raisedTokenDeposited.toString())).toString()
+        )
+    });
+
+    it("The position holder can claim its share of the trading fees", async () => {
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+        const position = await ctx.program.account.position.fetchNullable(ctx.fundCollectionToVestingPhaseUserPosition)
+
+        const claimPositionFees = () => ctx.program.methods
+            .claimPositionFees()
+            .accountsPartial({
+                user: ctx.user.publicKey,
+                lbp: ctx.fundCollectionToVestingPhaseLbp,
+                position: ctx.fundCollectionToVestingPhaseUserPosition,
+                positionMint: ctx.fundCollectionToVestingPhaseUserPositionMintKp.publicKey,
+                // @ts-ignore
+                launchedTokenMint: lbp.launchedTokenMint,
+                raisedTokenMint: lbp.raisedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.user])
+            .rpc()
+
+        const userRaisedTokenAta = getAssociatedTokenAddressSync(lbp.raisedTokenMint, ctx.user.publicKey)
+        const userRaisedTokenBalBefore = (await getAccount(ctx.connection, userRaisedTokenAta)).amount
+
+        await claimPositionFees()
+
+        // The position holds all the deposits, so it claims all the fees but the rounding of the shares
+        const expectedRaisedTokenFees = (BigInt(lbp.raisedTokenFeePerShareX64.toString()) * BigInt(position.amount.toString())) >> BigInt(64)
+        assert.ok(expectedRaisedTokenFees <= BigInt(lbp.raisedTokenHolderFees.toString()))
+        assert.ok(expectedRaisedTokenFees + BigInt(1) >= BigInt(lbp.raisedTokenHolderFees.toString()))
+
+        const userRaisedTokenBalAfter = (await getAccount(ctx.connection, userRaisedTokenAta)).amount
+        assert.equal(userRaisedTokenBalAfter - userRaisedTokenBalBefore, expectedRaisedTokenFees)
+
+        const claimedLbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+        assert.deepEqual(
+            claimedLbp.raisedTokenHolderFees.toString(),
+            (BigInt(lbp.raisedTokenHolderFees.toString()) - expectedRaisedTokenFees).toString()
+        )
+
+        const claimedPosition = await ctx.program.account.position.fetchNullable(ctx.fundCollectionToVestingPhaseUserPosition)
+        assert.deepEqual(claimedPosition.raisedTokenFeeCheckpointX64.toString(), lbp.raisedTokenFeePerShareX64.toString())
+        assert.deepEqual(claimedPosition.launchedTokenFeeCheckpointX64.toString(), lbp.launchedTokenFeePerShareX64.toString())
+
+        await assert.rejects(claimPositionFees())
+    });
+
+    it("The project can claim the lp tokens once unlocked", async () => {
+        let lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+
+        assert.deepEqual(lbp.lpMint, lpMintAddress)
+        assert.notEqual(lbp.lpTokenAmount.toNumber(), 0)
+
+        await ctx.program.methods
+            .projectClaimLp()
+            .accountsPartial({
+                project: ctx.project.publicKey,
+                lbp: ctx.fundCollectionToVestingPhaseLbp,
+                // @ts-ignore
+                lpMint: lpMintAddress,
+                lbpLpTokenAccount: creatorLpTokenAddress,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.project])
+            .rpc()
+
+        lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+
+        assert.deepEqual(lbp.lpTokenClaimed.toNumber(), lbp.lpTokenAmount.toNumber())
+
+        const projectLpToken = await getAccount(
+            ctx.connection,
+            getAssociatedTokenAddressSync(lpMintAddress, ctx.project.publicKey)
+        )
+        assert.deepEqual(Number(projectLpToken.amount), lbp.lpTokenAmount.toNumber())
+
+        // Everything unlocked has been claimed
+        await assert.rejects(
+            ctx.program.methods
+                .projectClaimLp()
+                .accountsPartial({
+                    project: ctx.project.publicKey,
+                    lbp: ctx.fundCollectionToVestingPhaseLbp,
+                    // @ts-ignore
+                    lpMint: lpMintAddress,
+                    lbpLpTokenAccount: creatorLpTokenAddress,
+                    tokenProgram: TOKEN_PROGRAM_ID
+                })
+                .signers([ctx.project])
+                .rpc()
+        )
+    });
+
+    it("The project can claim its treasury once unlocked", async () => {
+        let lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+
+        assert.notEqual(lbp.raisedTokenTreasury.toNumber(), 0)
+
+        await ctx.program.methods
+            .projectClaimTreasury()
+            .accountsPartial({
+                project: ctx.project.publicKey,
+                lbp: ctx.fundCollectionToVestingPhaseLbp,
+                // @ts-ignore
+                raisedTokenMint: lbp.raisedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.project])
+            .rpc()
+
+        lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+
+        assert.deepEqual(lbp.raisedTokenTreasuryClaimed.toNumber(), lbp.raisedTokenTreasury.toNumber())
+    });
+
+    it("The project cannot request a tranche of a vested treasury", async () => {
+        let lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+
+        const proposal = PublicKey.findProgramAddressSync(
+            [
+                anchor.utils.bytes.utf8.encode("proposal"),
+                ctx.fundCollectionToVestingPhaseLbp.toBuffer(),
+                new BN(lbp.proposalCount).toArrayLike(Buffer, "le", 4),
+            ],
+            ctx.program.programId
+        )[0]
+
+        await assert.rejects(
+            ctx.program.methods
+                .requestTranche()
+                .accountsPartial({
+                    project: ctx.project.publicKey,
+                    lbp: ctx.fundCollectionToVestingPhaseLbp,
+                    proposal: proposal,
+                })
+                .signers([ctx.project])
+                .rpc()
+        )
+    });
+
+    it("A position holder can propose an emergency refund", async () => {
+        let lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+
+        const proposal = PublicKey.findProgramAddressSync(
+            [
+                anchor.utils.bytes.utf8.encode("proposal"),
+                ctx.fundCollectionToVestingPhaseLbp.toBuffer(),
+                new BN(lbp.proposalCount).toArrayLike(Buffer, "le", 4),
+            ],
+            ctx.program.programId
+        )[0]
+
+        await ctx.program.methods
+            .proposeEmergencyRefund()
+            .accountsPartial({
+                user: ctx.user.publicKey,
+                lbp: ctx.fundCollectionToVestingPhaseLbp,
+                proposal: proposal,
+                position: ctx.fundCollectionToVestingPhaseUserPosition,
+                positionMint: ctx.fundCollectionToVestingPhaseUserPositionMintKp.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.user])
+            .rpc()
+
+        lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+        // An emergency refund does not take the slot of the tranche requests
+        assert.deepEqual(lbp.openProposal, false)
+
+        // The voting window is empty, so the proposal is rejected without votes
+        await ctx.program.methods
+            .resolveProposal()
+            .accountsPartial({
+                payer: ctx.user.publicKey,
+                lbp: ctx.fundCollectionToVestingPhaseLbp,
+                proposal: proposal,
+                project: ctx.project.publicKey,
+                // @ts-ignore
+                raisedTokenMint: lbp.raisedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.user])
+            .rpc()
+
+        const proposalData = await ctx.program.account.proposal.fetchNullable(proposal);
+        assert.deepEqual(proposalData.status, {rejected: {}})
+
+        lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+        assert.deepEqual(lbp.phase, {vesting: {}})
+        assert.deepEqual(lbp.openProposal, false)
+    });
+
+    it("The project can grant and revoke vesting launched tokens", async () => {
+        let lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+
+        const projectLaunchedTokenAta = getAssociatedTokenAddressSync(lbp.launchedTokenMint, ctx.project.publicKey)
+        await mintTo(ctx.connection, ctx.project, lbp.launchedTokenMint, projectLaunchedTokenAta, ctx.project.publicKey, 10 ** 9)
+
+        const grant = ctx.grant(ctx.fundCollectionToVestingPhaseLbp, lbp.grantCount)
+
+        await ctx.program.methods
+            .createGrant(
+                ctx.user.publicKey,
+                new BN(10 ** 9),
+                {linear: {cliffDuration: new BN(365 * 24 * 3600), vestingDuration: new BN(0)}},
+                true
+            )
+            .accountsPartial({
+                project: ctx.project.publicKey,
+                lbp: ctx.fundCollectionToVestingPhaseLbp,
+                grant: grant,
+                launchedTokenMint: lbp.launchedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.project])
+            .rpc()
+
+        lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+        assert.deepEqual(lbp.launchedTokenGranted.toNumber(), 10 ** 9)
+
+        // Nothing has vested before the cliff
+        await assert.rejects(
+            ctx.program.methods
+                .claimGrant()
+                .accountsPartial({
+                    beneficiary: ctx.user.publicKey,
+                    lbp: ctx.fundCollectionToVestingPhaseLbp,
+                    grant: grant,
+                    launchedTokenMint: lbp.launchedTokenMint,
+                    tokenProgram: TOKEN_PROGRAM_ID
+                })
+                .signers([ctx.user])
+                .rpc()
+        )
+
+        await ctx.program.methods
+            .revokeGrant()
+            .accountsPartial({
+                project: ctx.project.publicKey,
+                lbp: ctx.fundCollectionToVestingPhaseLbp,
+                grant: grant,
+                launchedTokenMint: lbp.launchedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.project])
+            .rpc()
+
+        const grantData = await ctx.program.account.grant.fetchNullable(grant);
+        assert.deepEqual(grantData.revoked, true)
+        assert.deepEqual(grantData.amount.toNumber(), 0)
+
+        lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+        assert.deepEqual(lbp.launchedTokenGranted.toNumber(), 0)
+    });
+
+    it("The vaults of a launched lbp cannot be closed before its claim deadline", async () => {
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+
+        await assert.rejects(
+            ctx.program.methods
+                .closeLbpVault()
+                .accountsPartial({
+                    authority: ctx.adminAuthority.publicKey,
+                    adminProposal: null,
+                    payer: lbp.payer,
+                    lbp: ctx.fundCollectionToVestingPhaseLbp,
+                    mint: lbp.launchedTokenMint,
+                    tokenProgram: TOKEN_PROGRAM_ID
+                })
+                .signers([ctx.adminAuthority])
+                .rpc()
+        )
+    });
+
+    it("The admin cannot sweep the lbp before its claim deadline", async () => {
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+
+        await assert.rejects(
+            ctx.program.methods
+                .sweepLbp({project: {}})
+                .accountsPartial({
+                    authority: ctx.adminAuthority.publicKey,
+                    adminProposal: null,
+                    lbp: ctx.fundCollectionToVestingPhaseLbp,
+                    destination: ctx.project.publicKey,
+                    launchedTokenMint: lbp.launchedTokenMint,
+                    raisedTokenMint: lbp.raisedTokenMint,
+                    tokenProgram: TOKEN_PROGRAM_ID
+                })
+                .signers([ctx.adminAuthority])
+                .rpc()
+        )
+    });
+
+    it("A position can be closed once its launched tokens and trading fees are claimed", async () => {
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+        const position = await ctx.program.account.position.fetchNullable(ctx.fundCollectionToVestingPhaseUserPosition)
+
+        const closePosition = () => ctx.program.methods
+            .closePosition()
+            .accountsPartial({
+                user: ctx.user.publicKey,
+                payer: position.payer,
+                lbp: ctx.fundCollectionToVestingPhaseLbp,
+                position: ctx.fundCollectionToVestingPhaseUserPosition,
+                positionMint: ctx.fundCollectionToVestingPhaseUserPositionMintKp.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.user])
+            .rpc()
+
+        // The launched tokens of the position are still unclaimed
+        await assert.rejects(closePosition())
+
+        await ctx.program.methods
+            .userClaim()
+            .accountsPartial({
+                user: ctx.user.publicKey,
+                lbp: ctx.fundCollectionToVestingPhaseLbp,
+                position: ctx.fundCollectionToVestingPhaseUserPosition,
+                positionMint: ctx.fundCollectionToVestingPhaseUserPositionMintKp.publicKey,
+                // @ts-ignore
+                launchedTokenMint: lbp.launchedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.user])
+            .rpc()
+
+        await closePosition()
+
+        assert.equal(await ctx.program.account.position.fetchNullable(ctx.fundCollectionToVestingPhaseUserPosition), null)
+
+        const closedLbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+        assert.deepEqual(closedLbp.positionCount, lbp.positionCount - 1)
+        assert.deepEqual(closedLbp.raisedTokenDeposited.toNumber(), lbp.raisedTokenDeposited.toNumber() - position.amount.toNumber())
+    });
+});
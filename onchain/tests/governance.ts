@@ -0,0 +1,371 @@
+import { Context } from "./helpers/context"
+import * as assert from "assert";
+import {BN} from "@coral-xyz/anchor";
+import {TOKEN_PROGRAM_ID} from "@coral-xyz/anchor/dist/cjs/utils/token";
+import * as anchor from "@coral-xyz/anchor";
+import {getAccount, getAssociatedTokenAddressSync} from "@solana/spl-token";
+import {PublicKey} from "@solana/web3.js";
+
+describe("Governance", () => {
+    let ctx: Context
+
+    before('Init context', async function () {
+        ctx = new Context()
+        await ctx.init()
+
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.governanceLbp);
+
+        await ctx.program.methods
+            .finalizeSale()
+            .accountsPartial({
+                adminAuthority: ctx.adminAuthority.publicKey,
+                lbp: ctx.governanceLbp,
+                // @ts-ignore
+                raisedTokenMint: lbp.raisedTokenMint,
+                launchedTokenMint: lbp.launchedTokenMint,
+                feeTreasury: ctx.adminAuthority.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+    })
+
+    function proposalAddress(index: number): PublicKey {
+        return PublicKey.findProgramAddressSync(
+            [
+                anchor.utils.bytes.utf8.encode("proposal"),
+                ctx.governanceLbp.toBuffer(),
+                new BN(index).toArrayLike(Buffer, "le", 4),
+            ],
+            ctx.program.programId
+        )[0]
+    }
+
+    function proposeEmergencyRefund(proposal: PublicKey) {
+        return ctx.program.methods
+            .proposeEmergencyRefund()
+            .accountsPartial({
+                user: ctx.user.publicKey,
+                lbp: ctx.governanceLbp,
+                proposal: proposal,
+                position: ctx.governanceUserPosition,
+                positionMint: ctx.governanceUserPositionMintKp.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.user])
+            .rpc()
+    }
+
+    function voteProposal(proposal: PublicKey, approve: boolean) {
+        return ctx.program.methods
+            .voteProposal(approve)
+            .accountsPartial({
+                user: ctx.user.publicKey,
+                lbp: ctx.governanceLbp,
+                proposal: proposal,
+                position: ctx.governanceUserPosition,
+                positionMint: ctx.governanceUserPositionMintKp.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.user])
+            .rpc()
+    }
+
+    async function resolveProposal(proposal: PublicKey) {
+        // Wait for the end of the voting window
+        await new Promise(resolve => setTimeout(resolve, 4_000))
+
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.governanceLbp);
+
+        await ctx.program.methods
+            .resolveProposal()
+            .accountsPartial({
+                payer: ctx.user.publicKey,
+                lbp: ctx.governanceLbp,
+                proposal: proposal,
+                project: ctx.project.publicKey,
+                // @ts-ignore
+                raisedTokenMint: lbp.raisedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.user])
+            .rpc()
+    }
+
+    it("An emergency refund cannot be proposed before the pool is created", async () => {
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.governanceLbp);
+
+        assert.deepEqual(lbp.poolStatus, {poolPending: {}})
+
+        await assert.rejects(proposeEmergencyRefund(proposalAddress(lbp.proposalCount)))
+    });
+
+    it("It can create the pool of the lbp", async () => {
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.governanceLbp);
+
+        const [token0Mint, token1Mint] = Buffer.compare(lbp.raisedTokenMint.toBuffer(), lbp.launchedTokenMint.toBuffer()) < 0
+            ? [lbp.raisedTokenMint, lbp.launchedTokenMint]
+            : [lbp.launchedTokenMint, lbp.raisedTokenMint]
+
+        const poolStateAddress = PublicKey.findProgramAddressSync(
+            [
+                anchor.utils.bytes.utf8.encode("pool"),
+                ctx.raydiumCpmmAmmConfig.toBuffer(),
+                token0Mint.toBuffer(),
+                token1Mint.toBuffer()
+            ],
+            ctx.raydiumCpmmProgramId
+        )[0]
+
+        const lpMintAddress = PublicKey.findProgramAddressSync(
+            [
+                anchor.utils.bytes.utf8.encode("pool_lp_mint"),
+                poolStateAddress.toBuffer(),
+            ],
+            ctx.raydiumCpmmProgramId
+        )[0]
+
+        const poolVaultAddress = (mint: PublicKey) => PublicKey.findProgramAddressSync(
+            [
+                anchor.utils.bytes.utf8.encode("pool_vault"),
+                poolStateAddress.toBuffer(),
+                mint.toBuffer(),
+            ],
+            ctx.raydiumCpmmProgramId
+        )[0]
+
+        const observationStateAddress = PublicKey.findProgramAddressSync(
+            [
+                anchor.utils.bytes.utf8.encode("observation"),
+                poolStateAddress.toBuffer(),
+            ],
+            ctx.raydiumCpmmProgramId
+        )[0]
+
+        await ctx.program.methods
+            .createPool()
+            .accountsPartial({
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null,
+                lbp: ctx.governanceLbp,
+                // @ts-ignore
+                raisedTokenMint: lbp.raisedTokenMint,
+                launchedTokenMint: lbp.launchedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .remainingAccounts([
+                {pubkey: ctx.raydiumCpmmProgramId, isSigner: false, isWritable: false},
+                {pubkey: ctx.raydiumCpmmAmmConfig, isSigner: false, isWritable: false},
+                {pubkey: ctx.raydiumCpmmAuthority, isSigner: false, isWritable: false},
+                {pubkey: poolStateAddress, isSigner: false, isWritable: true},
+                {pubkey: lpMintAddress, isSigner: false, isWritable: true},
+                {pubkey: getAssociatedTokenAddressSync(lpMintAddress, ctx.vaultAuthority(ctx.governanceLbp), true), isSigner: false, isWritable: true},
+                {pubkey: poolVaultAddress(lbp.launchedTokenMint), isSigner: false, isWritable: true},
+                {pubkey: poolVaultAddress(lbp.raisedTokenMint), isSigner: false, isWritable: true},
+                {pubkey: ctx.raydiumCpmmAmmCreatePoolFeeReceiver, isSigner: false, isWritable: true},
+                {pubkey: observationStateAddress, isSigner: false, isWritable: true},
+            ])
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        const createdLbp = await ctx.program.account.lbp.fetchNullable(ctx.governanceLbp);
+        assert.deepEqual(createdLbp.poolStatus, {poolCreated: {}})
+    });
+
+    it("The position holders can claim their launched tokens once vested", async () => {
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.governanceLbp);
+        const position = await ctx.program.account.position.fetchNullable(ctx.governanceUserPosition)
+
+        const userClaim = () => ctx.program.methods
+            .userClaim()
+            .accountsPartial({
+                user: ctx.user.publicKey,
+                lbp: ctx.governanceLbp,
+                position: ctx.governanceUserPosition,
+                positionMint: ctx.governanceUserPositionMintKp.publicKey,
+                // @ts-ignore
+                launchedTokenMint: lbp.launchedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.user])
+            .rpc()
+
+        const userLaunchedTokenAtaAddress = getAssociatedTokenAddressSync(lbp.launchedTokenMint, ctx.user.publicKey)
+        const userLaunchedTokenBalBefore = await getAccount(ctx.connection, userLaunchedTokenAtaAddress)
+            .then(account => account.amount)
+            .catch(() => BigInt(0))
+
+        await userClaim()
+
+        // The launched tokens not used for the liquidity are vested pro rata of the deposits, all at once
+        // with the schedule of the lbp
+        const expectedAmount = lbp.launchedTokenReward.mul(position.amount).div(lbp.raisedTokenCap)
+        assert.notEqual(expectedAmount.toNumber(), 0)
+
+        const userLaunchedTokenBalAfter = await getAccount(ctx.connection, userLaunchedTokenAtaAddress)
+        assert.equal(userLaunchedTokenBalAfter.amount - userLaunchedTokenBalBefore, BigInt(expectedAmount.toNumber()))
+
+        const claimedPosition = await ctx.program.account.position.fetchNullable(ctx.governanceUserPosition)
+        assert.deepEqual(claimedPosition.launchedTokenClaimed.toNumber(), expectedAmount.toNumber())
+
+        const claimedLbp = await ctx.program.account.lbp.fetchNullable(ctx.governanceLbp);
+        assert.deepEqual(claimedLbp.launchedTokenRewardClaimed.toNumber(), expectedAmount.toNumber())
+
+        await assert.rejects(userClaim())
+    });
+
+    it("The position holders can approve the release of a tranche", async () => {
+        let lbp = await ctx.program.account.lbp.fetchNullable(ctx.governanceLbp);
+        const proposal = proposalAddress(lbp.proposalCount)
+
+        const projectRaisedTokenAtaAddress = getAssociatedTokenAddressSync(
+            lbp.raisedTokenMint,
+            ctx.project.publicKey
+        )
+        const projectRaisedTokenBalBefore = await getAccount(ctx.connection, projectRaisedTokenAtaAddress)
+            .then(account => account.amount)
+            .catch(() => BigInt(0))
+
+        await ctx.program.methods
+            .requestTranche()
+            .accountsPartial({
+                project: ctx.project.publicKey,
+                lbp: ctx.governanceLbp,
+                proposal: proposal,
+            })
+            .signers([ctx.project])
+            .rpc()
+
+        lbp = await ctx.program.account.lbp.fetchNullable(ctx.governanceLbp);
+        assert.deepEqual(lbp.openProposal, true)
+
+        await voteProposal(proposal, true)
+        await resolveProposal(proposal)
+
+        const proposalData = await ctx.program.account.proposal.fetchNullable(proposal);
+        assert.deepEqual(proposalData.status, {approved: {}})
+
+        // The first of the two tranches is half of the treasury
+        lbp = await ctx.program.account.lbp.fetchNullable(ctx.governanceLbp);
+        assert.notEqual(proposalData.amount.toNumber(), 0)
+        assert.deepEqual(proposalData.amount.toNumber(), Math.floor(lbp.raisedTokenTreasury.toNumber() / 2))
+        assert.deepEqual(lbp.treasuryTranchesReleased, 1)
+        assert.deepEqual(lbp.raisedTokenTreasuryClaimed.toNumber(), proposalData.amount.toNumber())
+        assert.deepEqual(lbp.openProposal, false)
+
+        const projectRaisedTokenBalAfter = await getAccount(ctx.connection, projectRaisedTokenAtaAddress)
+        assert.equal(projectRaisedTokenBalAfter.amount - projectRaisedTokenBalBefore, BigInt(proposalData.amount.toNumber()))
+    });
+
+    it("The position holders can approve an emergency refund", async () => {
+        let lbp = await ctx.program.account.lbp.fetchNullable(ctx.governanceLbp);
+        const proposal = proposalAddress(lbp.proposalCount)
+
+        await proposeEmergencyRefund(proposal)
+        await voteProposal(proposal, true)
+        await resolveProposal(proposal)
+
+        const proposalData = await ctx.program.account.proposal.fetchNullable(proposal);
+        assert.deepEqual(proposalData.status, {approved: {}})
+
+        lbp = await ctx.program.account.lbp.fetchNullable(ctx.governanceLbp);
+        assert.deepEqual(lbp.phase, {emergencyRefund: {}})
+        assert.notEqual(lbp.emergencyRefundAmount.toNumber(), 0)
+        assert.deepEqual(
+            lbp.emergencyRefundAmount.toNumber(),
+            lbp.raisedTokenTreasury.toNumber() - lbp.raisedTokenTreasuryClaimed.toNumber()
+        )
+    });
+
+    it("The referrers cannot claim their reward after an emergency refund", async () => {
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.governanceLbp);
+
+        await assert.rejects(
+            ctx.program.methods
+                .claimReferralReward()
+                .accountsPartial({
+                    referrer: ctx.referrer.publicKey,
+                    lbp: ctx.governanceLbp,
+                    referral: ctx.referral(ctx.governanceLbp, ctx.referrer.publicKey),
+                    // @ts-ignore
+                    launchedTokenMint: lbp.launchedTokenMint,
+                    raisedTokenMint: lbp.raisedTokenMint,
+                    tokenProgram: TOKEN_PROGRAM_ID
+                })
+                .signers([ctx.referrer])
+                .rpc()
+        )
+    });
+
+    it("A position cannot be closed before it is refunded", async () => {
+        const position = await ctx.program.account.position.fetchNullable(ctx.governanceUserPosition)
+
+        await assert.rejects(
+            ctx.program.methods
+                .closePosition()
+                .accountsPartial({
+                    user: ctx.user.publicKey,
+                    payer: position.payer,
+                    lbp: ctx.governanceLbp,
+                    position: ctx.governanceUserPosition,
+                    positionMint: ctx.governanceUserPositionMintKp.publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID
+                })
+                .signers([ctx.user])
+                .rpc()
+        )
+    });
+
+    it("The positions are refunded pro rata of their deposit", async () => {
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.governanceLbp);
+        const position = await ctx.program.account.position.fetchNullable(ctx.governanceUserPosition)
+
+        const raisedTokenUserAtaAddress = getAssociatedTokenAddressSync(
+            lbp.raisedTokenMint,
+            ctx.user.publicKey
+        )
+
+        const raisedTokenUserBalBefore = await getAccount(
+            ctx.connection,
+            raisedTokenUserAtaAddress
+        )
+
+        await ctx.program.methods
+            .userRefund()
+            .accountsPartial({
+                user: ctx.user.publicKey,
+                config: ctx.config,
+                lbp: ctx.governanceLbp,
+                payer: position.payer,
+                positionMint: position.mint,
+                position: ctx.governanceUserPosition,
+                userPositionAta: getAssociatedTokenAddressSync(position.mint, ctx.user.publicKey),
+                raisedTokenMint: lbp.raisedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.user])
+            .rpc()
+
+        const raisedTokenUserBalAfter = await getAccount(
+            ctx.connection,
+            raisedTokenUserAtaAddress
+        )
+
+        // The position deposited 400_000 of the 500_000 raised
+        assert.equal(position.amount.toNumber(), 400_000)
+        assert.equal(
+            raisedTokenUserBalAfter.amount - raisedTokenUserBalBefore.amount,
+            BigInt(Math.floor(lbp.emergencyRefundAmount.toNumber() * 4 / 5))
+        )
+
+        assert.equal(await ctx.program.account.position.fetchNullable(ctx.governanceUserPosition), null)
+
+        // What is left of the treasury is owed to the position still open
+        const refundedLbp = await ctx.program.account.lbp.fetchNullable(ctx.governanceLbp);
+        assert.equal(refundedLbp.raisedTokenDeposited.toNumber(), 100_000)
+        assert.equal(
+            refundedLbp.emergencyRefundAmount.toNumber(),
+            lbp.emergencyRefundAmount.toNumber() - Math.floor(lbp.emergencyRefundAmount.toNumber() * 4 / 5)
+        )
+    });
+});
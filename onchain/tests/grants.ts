@@ -0,0 +1,197 @@
+import { Context } from "./helpers/context"
+import * as assert from "assert";
+import {BN} from "@coral-xyz/anchor";
+import {TOKEN_PROGRAM_ID} from "@coral-xyz/anchor/dist/cjs/utils/token";
+import {getAccount, getAssociatedTokenAddressSync, mintTo} from "@solana/spl-token";
+import {Keypair, PublicKey} from "@solana/web3.js";
+
+describe("Grants", () => {
+    let ctx: Context
+    let beneficiary: Keypair
+
+    before('Init context', async function () {
+        ctx = new Context()
+        await ctx.init()
+
+        beneficiary = Keypair.generate()
+        await ctx.connection.confirmTransaction(
+            await ctx.connection.requestAirdrop(beneficiary.publicKey, 10 ** 9)
+        )
+    })
+
+    async function createGrant(lbpAddress: PublicKey, amount: number, schedule: any, revocable: boolean): Promise<PublicKey> {
+        const lbp = await ctx.program.account.lbp.fetch(lbpAddress);
+        const grant = ctx.grant(lbpAddress, lbp.grantCount)
+
+        const projectLaunchedTokenAta = getAssociatedTokenAddressSync(lbp.launchedTokenMint, ctx.project.publicKey)
+        await mintTo(ctx.connection, ctx.project, lbp.launchedTokenMint, projectLaunchedTokenAta, ctx.project.publicKey, amount)
+
+        await ctx.program.methods
+            .createGrant(beneficiary.publicKey, new BN(amount), schedule, revocable)
+            .accountsPartial({
+                project: ctx.project.publicKey,
+                lbp: lbpAddress,
+                grant: grant,
+                launchedTokenMint: lbp.launchedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.project])
+            .rpc()
+
+        return grant
+    }
+
+    async function claimGrant(lbpAddress: PublicKey, grant: PublicKey) {
+        const lbp = await ctx.program.account.lbp.fetch(lbpAddress);
+
+        await ctx.program.methods
+            .claimGrant()
+            .accountsPartial({
+                beneficiary: beneficiary.publicKey,
+                lbp: lbpAddress,
+                grant: grant,
+                launchedTokenMint: lbp.launchedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([beneficiary])
+            .rpc()
+    }
+
+    async function revokeGrant(lbpAddress: PublicKey, grant: PublicKey) {
+        const lbp = await ctx.program.account.lbp.fetch(lbpAddress);
+
+        await ctx.program.methods
+            .revokeGrant()
+            .accountsPartial({
+                project: ctx.project.publicKey,
+                lbp: lbpAddress,
+                grant: grant,
+                launchedTokenMint: lbp.launchedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.project])
+            .rpc()
+    }
+
+    async function beneficiaryBalance(lbpAddress: PublicKey): Promise<bigint> {
+        const lbp = await ctx.program.account.lbp.fetch(lbpAddress);
+
+        return getAccount(ctx.connection, getAssociatedTokenAddressSync(lbp.launchedTokenMint, beneficiary.publicKey))
+            .then(account => account.amount)
+            .catch(() => BigInt(0))
+    }
+
+    it("A grant can be created alongside the sale", async () => {
+        const grant = await createGrant(
+            ctx.fundCollectionPhaseLbp,
+            1_000,
+            {linear: {cliffDuration: new BN(0), vestingDuration: new BN(0)}},
+            true
+        )
+
+        const grantData = await ctx.program.account.grant.fetch(grant);
+        assert.deepEqual(grantData.beneficiary, beneficiary.publicKey)
+        assert.equal(grantData.amount.toNumber(), 1_000)
+
+        // The vesting only starts with the pool
+        await assert.rejects(claimGrant(ctx.fundCollectionPhaseLbp, grant))
+    });
+
+    it("A vested grant cannot be claimed twice", async () => {
+        const grant = await createGrant(
+            ctx.fundCollectionToVestingPhaseLbp,
+            1_000,
+            {linear: {cliffDuration: new BN(0), vestingDuration: new BN(0)}},
+            false
+        )
+
+        const balanceBefore = await beneficiaryBalance(ctx.fundCollectionToVestingPhaseLbp)
+        await claimGrant(ctx.fundCollectionToVestingPhaseLbp, grant)
+        const balanceAfter = await beneficiaryBalance(ctx.fundCollectionToVestingPhaseLbp)
+        assert.equal(balanceAfter - balanceBefore, BigInt(1_000))
+
+        await assert.rejects(claimGrant(ctx.fundCollectionToVestingPhaseLbp, grant))
+
+        const grantData = await ctx.program.account.grant.fetch(grant);
+        assert.equal(grantData.claimed.toNumber(), 1_000)
+    });
+
+    it("A beneficiary can receive several grants under the same lbp", async () => {
+        const lbp = await ctx.program.account.lbp.fetch(ctx.fundCollectionToVestingPhaseLbp);
+
+        const firstGrant = await createGrant(
+            ctx.fundCollectionToVestingPhaseLbp,
+            2_000,
+            {linear: {cliffDuration: new BN(365 * 24 * 3600), vestingDuration: new BN(0)}},
+            false
+        )
+        const secondGrant = await createGrant(
+            ctx.fundCollectionToVestingPhaseLbp,
+            3_000,
+            {linear: {cliffDuration: new BN(365 * 24 * 3600), vestingDuration: new BN(0)}},
+            false
+        )
+
+        assert.notDeepEqual(firstGrant, secondGrant)
+
+        const updatedLbp = await ctx.program.account.lbp.fetch(ctx.fundCollectionToVestingPhaseLbp);
+        assert.equal(updatedLbp.grantCount, lbp.grantCount + 2)
+        assert.equal(updatedLbp.launchedTokenGranted.toNumber(), lbp.launchedTokenGranted.toNumber() + 5_000)
+    });
+
+    it("A grant which is not revocable cannot be revoked", async () => {
+        const lbp = await ctx.program.account.lbp.fetch(ctx.fundCollectionToVestingPhaseLbp);
+
+        // The last grant of the previous test is not revocable
+        await assert.rejects(
+            revokeGrant(ctx.fundCollectionToVestingPhaseLbp, ctx.grant(ctx.fundCollectionToVestingPhaseLbp, lbp.grantCount - 1))
+        )
+    });
+
+    it("A revoked grant keeps what vested before its revocation", async () => {
+        const grant = await createGrant(
+            ctx.fundCollectionToVestingPhaseLbp,
+            10 ** 9,
+            {linear: {cliffDuration: new BN(0), vestingDuration: new BN(10 ** 6)}},
+            true
+        )
+
+        // The vesting of the lbp started with its pool, so a part has already vested
+        await claimGrant(ctx.fundCollectionToVestingPhaseLbp, grant)
+        const claimed = (await ctx.program.account.grant.fetch(grant)).claimed.toNumber()
+        assert.ok(claimed > 0)
+        assert.ok(claimed < 10 ** 9)
+
+        const lbp = await ctx.program.account.lbp.fetch(ctx.fundCollectionToVestingPhaseLbp);
+        const projectLaunchedTokenAta = getAssociatedTokenAddressSync(lbp.launchedTokenMint, ctx.project.publicKey)
+        const projectBalanceBefore = (await getAccount(ctx.connection, projectLaunchedTokenAta)).amount
+
+        await revokeGrant(ctx.fundCollectionToVestingPhaseLbp, grant)
+
+        const grantData = await ctx.program.account.grant.fetch(grant);
+        assert.deepEqual(grantData.revoked, true)
+        assert.ok(grantData.amount.toNumber() >= claimed)
+
+        // The unvested part goes back to the project
+        const projectBalanceAfter = (await getAccount(ctx.connection, projectLaunchedTokenAta)).amount
+        assert.equal(projectBalanceAfter - projectBalanceBefore, BigInt(10 ** 9 - grantData.amount.toNumber()))
+
+        const revokedLbp = await ctx.program.account.lbp.fetch(ctx.fundCollectionToVestingPhaseLbp);
+        assert.equal(
+            revokedLbp.launchedTokenGranted.toNumber(),
+            lbp.launchedTokenGranted.toNumber() - (10 ** 9 - grantData.amount.toNumber())
+        )
+
+        // A grant cannot be revoked twice
+        await assert.rejects(revokeGrant(ctx.fundCollectionToVestingPhaseLbp, grant))
+
+        // Only the part vested before the revocation is left to claim
+        if (grantData.amount.toNumber() > claimed) {
+            await claimGrant(ctx.fundCollectionToVestingPhaseLbp, grant)
+        }
+        await assert.rejects(claimGrant(ctx.fundCollectionToVestingPhaseLbp, grant))
+
+        const claimedGrant = await ctx.program.account.grant.fetch(grant);
+        assert.equal(claimedGrant.claimed.toNumber(), grantData.amount.toNumber())
+    });
+});
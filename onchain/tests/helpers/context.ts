@@ -29,6 +29,7 @@ export class Context {
     public whitelistAuthority: Keypair;
     public project: Keypair;
     public user: Keypair;
+    public referrer: Keypair;
 
     public program: anchor.Program<Borgpad>;
     public config: PublicKey;
@@ -52,6 +53,34 @@ export class Context {
     public fundCollectionToVestingPhaseUserPositionMintKp: Keypair;
     public fundCollectionToVestingPhaseUserPosition: PublicKey;
 
+    public fundCollectionToCancelledLbpUid: number = 47;
+    public fundCollectionToCancelledLbp: PublicKey;
+
+    public crankRefundPhaseLbpUid: number = 48;
+    public crankRefundPhaseLbp: PublicKey;
+    public crankRefundPhaseUserPositionMintKp: Keypair;
+    public crankRefundPhaseUserPosition: PublicKey;
+
+    public updatableLbpUid: number = 49;
+    public updatableLbp: PublicKey;
+
+    public referredLbpUid: number = 50;
+    public referredLbp: PublicKey;
+    public referredUserPositionMintKp: Keypair;
+    public referredUserPosition: PublicKey;
+
+    public governanceLbpUid: number = 51;
+    public governanceLbp: PublicKey;
+    public governanceUserPositionMintKp: Keypair;
+    public governanceUserPosition: PublicKey;
+    public governanceSecondUserPositionMintKp: Keypair;
+    public governanceSecondUserPosition: PublicKey;
+
+    public sweepLbpUid: number = 52;
+    public sweepLbp: PublicKey;
+    public sweepUserPositionMintKp: Keypair;
+    public sweepUserPosition: PublicKey;
+
     public amount = new BN(420_000)
     public raisedTokenMinCap = new BN(500_000)
 
@@ -84,6 +113,13 @@ export class Context {
         }
     }
 
+    public vaultAuthority(lbpAddress: PublicKey): PublicKey {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("vault_authority"), lbpAddress.toBuffer()],
+            this.program.programId
+        )[0];
+    }
+
     private async initWalletContext() {
         this.deployer = Keypair.fromSeed(new Uint8Array(
             JSON.parse(Fs.readFileSync("tests/helpers/local_deployer.json").toString())
@@ -100,6 +136,9 @@ export class Context {
         this.user = Keypair.fromSeed(
             Uint8Array.from(sha256.digest("user"))
         );
+        this.referrer = Keypair.fromSeed(
+            Uint8Array.from(sha256.digest("referrer"))
+        );
     }
 
     private async initProgramContext() {
@@ -133,6 +172,36 @@ export class Context {
             [Buffer.from("lbp"), (new BN(this.fundCollectionToVestingPhaseLbpUid)).toArrayLike(Buffer, "le", 8)],
             this.program.programId
         )[0];
+
+        this.fundCollectionToCancelledLbp = PublicKey.findProgramAddressSync(
+            [Buffer.from("lbp"), (new BN(this.fundCollectionToCancelledLbpUid)).toArrayLike(Buffer, "le", 8)],
+            this.program.programId
+        )[0];
+
+        this.crankRefundPhaseLbp = PublicKey.findProgramAddressSync(
+            [Buffer.from("lbp"), (new BN(this.crankRefundPhaseLbpUid)).toArrayLike(Buffer, "le", 8)],
+            this.program.programId
+        )[0];
+
+        this.updatableLbp = PublicKey.findProgramAddressSync(
+            [Buffer.from("lbp"), (new BN(this.updatableLbpUid)).toArrayLike(Buffer, "le", 8)],
+            this.program.programId
+        )[0];
+
+        this.referredLbp = PublicKey.findProgramAddressSync(
+            [Buffer.from("lbp"), (new BN(this.referredLbpUid)).toArrayLike(Buffer, "le", 8)],
+            this.program.programId
+        )[0];
+
+        this.governanceLbp = PublicKey.findProgramAddressSync(
+            [Buffer.from("lbp"), (new BN(this.governanceLbpUid)).toArrayLike(Buffer, "le", 8)],
+            this.program.programId
+        )[0];
+
+        this.sweepLbp = PublicKey.findProgramAddressSync(
+            [Buffer.from("lbp"), (new BN(this.sweepLbpUid)).toArrayLike(Buffer, "le", 8)],
+            this.program.programId
+        )[0];
     }
 
     private async initPositionContext() {
@@ -153,6 +222,51 @@ export class Context {
             [Buffer.from("position"), this.fundCollectionToVestingPhaseLbp.toBuffer(), this.fundCollectionToVestingPhaseUserPositionMintKp.publicKey.toBuffer()],
             this.program.programId
         )[0];
+
+        this.crankRefundPhaseUserPositionMintKp = Keypair.fromSeed(
+            Uint8Array.from(sha256.digest("crankRefundPhaseUserPositionMintKp"))
+        );
+
+        this.crankRefundPhaseUserPosition = PublicKey.findProgramAddressSync(
+            [Buffer.from("position"), this.crankRefundPhaseLbp.toBuffer(), this.crankRefundPhaseUserPositionMintKp.publicKey.toBuffer()],
+            this.program.programId
+        )[0];
+
+        this.referredUserPositionMintKp = Keypair.fromSeed(
+            Uint8Array.from(sha256.digest("referredUserPositionMintKp"))
+        );
+
+        this.referredUserPosition = PublicKey.findProgramAddressSync(
+            [Buffer.from("position"), this.referredLbp.toBuffer(), this.referredUserPositionMintKp.publicKey.toBuffer()],
+            this.program.programId
+        )[0];
+
+        this.governanceUserPositionMintKp = Keypair.fromSeed(
+            Uint8Array.from(sha256.digest("governanceUserPositionMintKp"))
+        );
+
+        this.governanceUserPosition = PublicKey.findProgramAddressSync(
+            [Buffer.from("position"), this.governanceLbp.toBuffer(), this.governanceUserPositionMintKp.publicKey.toBuffer()],
+            this.program.programId
+        )[0];
+
+        this.governanceSecondUserPositionMintKp = Keypair.fromSeed(
+            Uint8Array.from(sha256.digest("governanceSecondUserPositionMintKp"))
+        );
+
+        this.governanceSecondUserPosition = PublicKey.findProgramAddressSync(
+            [Buffer.from("position"), this.governanceLbp.toBuffer(), this.governanceSecondUserPositionMintKp.publicKey.toBuffer()],
+            this.program.programId
+        )[0];
+
+        this.sweepUserPositionMintKp = Keypair.fromSeed(
+            Uint8Array.from(sha256.digest("sweepUserPositionMintKp"))
+        );
+
+        this.sweepUserPosition = PublicKey.findProgramAddressSync(
+            [Buffer.from("position"), this.sweepLbp.toBuffer(), this.sweepUserPositionMintKp.publicKey.toBuffer()],
+            this.program.programId
+        )[0];
     }
 
     private async airdrop() {
@@ -176,6 +290,10 @@ export class Context {
             this.user.publicKey,
             42 * LAMPORTS_PER_SOL
         );
+        const sig5 = await this.connection.requestAirdrop(
+            this.referrer.publicKey,
+            42 * LAMPORTS_PER_SOL
+        );
 
         const latestBlockHash = await this.connection.getLatestBlockhash();
 
@@ -204,6 +322,11 @@ export class Context {
             lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
             signature: sig4,
         });
+        await this.connection.confirmTransaction({
+            blockhash: latestBlockHash.blockhash,
+            lastValidBlockHeight: latestBlockHash.lastValidBlockHeight,
+            signature: sig5,
+        });
     }
 
     private async initConfig() {
@@ -224,6 +347,52 @@ export class Context {
     }
 
     private async initLbps() {
+        const [launchedTokenMint, raisedTokenMint] = await this.initMints()
+
+        await this.initLbp(this.fundCollectionPhaseLbpUid, launchedTokenMint, raisedTokenMint)
+
+        await this.initLbp(this.refundPhaseLbpUid, launchedTokenMint, raisedTokenMint)
+        await this.userDeposit(this.refundPhaseLbp, this.amount, raisedTokenMint, this.refundPhaseUserPositionMintKp, this.refundPhaseUserPosition)
+        await this.projectDeposit(this.refundPhaseLbp)
+        await this.moveToRefundPhase(this.refundPhaseLbp, launchedTokenMint, raisedTokenMint)
+
+        // TODO: vesting lbp
+
+        await this.initLbp(this.fundCollectionToRefundPhaseLbpUid, launchedTokenMint, raisedTokenMint)
+        await this.initLbp(this.fundCollectionToVestingPhaseLbpUid, launchedTokenMint, raisedTokenMint)
+        await this.userDeposit(this.fundCollectionToVestingPhaseLbp, this.raisedTokenMinCap, raisedTokenMint, this.fundCollectionToVestingPhaseUserPositionMintKp, this.fundCollectionToVestingPhaseUserPosition)
+        await this.projectDeposit(this.fundCollectionToVestingPhaseLbp)
+
+        await this.initLbp(this.fundCollectionToCancelledLbpUid, launchedTokenMint, raisedTokenMint)
+
+        await this.initLbp(this.crankRefundPhaseLbpUid, launchedTokenMint, raisedTokenMint)
+        await this.userDeposit(this.crankRefundPhaseLbp, this.amount, raisedTokenMint, this.crankRefundPhaseUserPositionMintKp, this.crankRefundPhaseUserPosition)
+        await this.moveToRefundPhase(this.crankRefundPhaseLbp, launchedTokenMint, raisedTokenMint)
+
+        await this.initLbp(this.updatableLbpUid, launchedTokenMint, raisedTokenMint)
+
+        await this.initLbp(this.referredLbpUid, launchedTokenMint, raisedTokenMint)
+        await this.userDeposit(this.referredLbp, this.raisedTokenMinCap, raisedTokenMint, this.referredUserPositionMintKp, this.referredUserPosition, this.referrer.publicKey)
+        await this.projectDeposit(this.referredLbp)
+
+        // The pool of this lbp is created by the governance tests, so it needs its own pair of mints
+        const [governanceLaunchedTokenMint, governanceRaisedTokenMint] = await this.initMints()
+        await this.initLbp(this.governanceLbpUid, governanceLaunchedTokenMint, governanceRaisedTokenMint, {
+            treasuryRelease: {milestones: {trancheCount: 2}},
+            governance: {votingDuration: new BN(3), quorumBps: 1_000, thresholdBps: 5_000},
+        })
+        await this.userDeposit(this.governanceLbp, new BN(400_000), governanceRaisedTokenMint, this.governanceUserPositionMintKp, this.governanceUserPosition)
+        await this.userDeposit(this.governanceLbp, new BN(100_000), governanceRaisedTokenMint, this.governanceSecondUserPositionMintKp, this.governanceSecondUserPosition, this.referrer.publicKey)
+        await this.projectDeposit(this.governanceLbp)
+
+        // The claim deadline of this lbp is already over, so that it can be swept
+        await this.initLbp(this.sweepLbpUid, launchedTokenMint, raisedTokenMint, {claimDeadline: new BN(1)})
+        await this.userDeposit(this.sweepLbp, this.amount, raisedTokenMint, this.sweepUserPositionMintKp, this.sweepUserPosition)
+        await this.projectDeposit(this.sweepLbp)
+        await this.moveToRefundPhase(this.sweepLbp, launchedTokenMint, raisedTokenMint)
+    }
+
+    private async initMints(): Promise<[PublicKey, PublicKey]> {
         const launchedTokenMint = await createMint(
             this.connection,
             this.project,
@@ -272,22 +441,10 @@ export class Context {
             42 * 10 ** 9
         )
 
-        await this.initLbp(this.fundCollectionPhaseLbpUid, launchedTokenMint, raisedTokenMint)
-
-        await this.initLbp(this.refundPhaseLbpUid, launchedTokenMint, raisedTokenMint)
-        await this.userDeposit(this.refundPhaseLbp, this.amount, raisedTokenMint, this.refundPhaseUserPositionMintKp, this.refundPhaseUserPosition)
-        await this.projectDeposit(this.refundPhaseLbp)
-        await this.moveToRefundPhase(this.refundPhaseLbp, launchedTokenMint, raisedTokenMint)
-
-        // TODO: vesting lbp
-
-        await this.initLbp(this.fundCollectionToRefundPhaseLbpUid, launchedTokenMint, raisedTokenMint)
-        await this.initLbp(this.fundCollectionToVestingPhaseLbpUid, launchedTokenMint, raisedTokenMint)
-        await this.userDeposit(this.fundCollectionToVestingPhaseLbp, this.raisedTokenMinCap, raisedTokenMint, this.fundCollectionToVestingPhaseUserPositionMintKp, this.fundCollectionToVestingPhaseUserPosition)
-        await this.projectDeposit(this.fundCollectionToVestingPhaseLbp)
+        return [launchedTokenMint, raisedTokenMint]
     }
 
-    private async initLbp(lbpUid: number, launchedTokenMint: PublicKey, raisedTokenMint: PublicKey): Promise<PublicKey> {
+    private async initLbp(lbpUid: number, launchedTokenMint: PublicKey, raisedTokenMint: PublicKey, overrides: object = {}): Promise<PublicKey> {
         const lbpInitalizeData = {
             uid: new BN(lbpUid),
 
@@ -300,9 +457,19 @@ export class Context {
             raisedTokenMint: raisedTokenMint,
             raisedTokenMinCap: this.raisedTokenMinCap,
             raisedTokenMaxCap: new BN(1_000_000),
+            raisedTokenLpDistribution: 80,
+
+            dex: {raydiumCpSwap: {}},
+            poolOpenTime: new BN(0),
+            lpLockPolicy: {timeLock: {duration: new BN(0)}},
+            tradingFeeSplit: {projectBps: 0, treasuryBps: 0},
 
-            cliffDuration: new BN(0),
-            vestingDuration: new BN(0),
+            vestingSchedule: {linear: {cliffDuration: new BN(0), vestingDuration: new BN(0)}},
+
+            treasuryRelease: {vesting: {cliffDuration: new BN(0), vestingDuration: new BN(0)}},
+            governance: {votingDuration: new BN(0), quorumBps: 0, thresholdBps: 5_000},
+            claimDeadline: new BN(4_102_444_800),
+            ...overrides,
         }
 
         const lbpPda = anchor.web3.PublicKey.findProgramAddressSync(
@@ -327,7 +494,21 @@ export class Context {
         return lbpPda[0]
     }
 
-    private async userDeposit(lbpAddress: PublicKey, amount: BN, raisedTokenMint: PublicKey, userPositionMintKp: Keypair, userPosition: PublicKey) {
+    public referral(lbpAddress: PublicKey, referrer: PublicKey): PublicKey {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("referral"), lbpAddress.toBuffer(), referrer.toBuffer()],
+            this.program.programId
+        )[0];
+    }
+
+    public grant(lbpAddress: PublicKey, index: number): PublicKey {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("grant"), lbpAddress.toBuffer(), new BN(index).toArrayLike(Buffer, "le", 4)],
+            this.program.programId
+        )[0];
+    }
+
+    private async userDeposit(lbpAddress: PublicKey, amount: BN, raisedTokenMint: PublicKey, userPositionMintKp: Keypair, userPosition: PublicKey, referrer: PublicKey = null) {
 
 
         const userPositionAta = getAssociatedTokenAddressSync(
@@ -338,12 +519,13 @@ export class Context {
         assert.equal(await this.program.account.position.fetchNullable(userPosition), null)
 
         await this.program.methods
-            .userDeposit(amount)
+            .userDeposit(amount, referrer)
             .accountsPartial({
                 whitelistAuthority: this.whitelistAuthority.publicKey,
                 user: this.user.publicKey,
                 config: this.config,
                 lbp: lbpAddress,
+                referral: referrer ? this.referral(lbpAddress, referrer) : null,
                 positionMint: userPositionMintKp.publicKey,
                 position: userPosition,
                 userPositionAta: userPositionAta,
@@ -49,9 +49,18 @@ describe("Initialize LBP", () => {
             raisedTokenMint: raisedTokenMint,
             raisedTokenMinCap: new BN(500_000),
             raisedTokenMaxCap: new BN(1_000_000),
+            raisedTokenLpDistribution: 80,
 
-            cliffDuration: new BN(0),
-            vestingDuration: new BN(0),
+            dex: {raydiumCpSwap: {}},
+            poolOpenTime: new BN(0),
+            lpLockPolicy: {timeLock: {duration: new BN(0)}},
+            tradingFeeSplit: {projectBps: 0, treasuryBps: 0},
+
+            vestingSchedule: {linear: {cliffDuration: new BN(0), vestingDuration: new BN(0)}},
+
+            treasuryRelease: {vesting: {cliffDuration: new BN(0), vestingDuration: new BN(0)}},
+            governance: {votingDuration: new BN(0), quorumBps: 0, thresholdBps: 5_000},
+            claimDeadline: new BN(4_102_444_800),
         }
 
         const lbpPda = anchor.web3.PublicKey.findProgramAddressSync(
@@ -62,13 +71,13 @@ describe("Initialize LBP", () => {
 
         const raisedTokenAta = getAssociatedTokenAddressSync(
             lbpInitalizeData.raisedTokenMint,
-            lbpPda[0],
+            ctx.vaultAuthority(lbpPda[0]),
             true
         )
 
         const launchedTokenAta = getAssociatedTokenAddressSync(
             lbpInitalizeData.launchedTokenMint,
-            lbpPda[0],
+            ctx.vaultAuthority(lbpPda[0]),
             true
         )
 
@@ -98,10 +107,18 @@ describe("Initialize LBP", () => {
         assert.deepEqual(lbp.raisedTokenMinCap.toNumber(), lbpInitalizeData.raisedTokenMinCap.toNumber());
         assert.deepEqual(lbp.raisedTokenMaxCap.toNumber(), lbpInitalizeData.raisedTokenMaxCap.toNumber());
         assert.deepEqual(lbp.raisedTokenCap.toNumber(), 0);
+        assert.deepEqual(lbp.dex, lbpInitalizeData.dex);
+        assert.deepEqual(lbp.poolOpenTime.toNumber(), lbpInitalizeData.poolOpenTime.toNumber());
+        assert.deepEqual(Object.keys(lbp.lpLockPolicy), ["timeLock"]);
+        assert.deepEqual(lbp.tradingFeeSplit, lbpInitalizeData.tradingFeeSplit);
         assert.deepEqual(lbp.phase, {fundCollection: {}});
         assert.deepEqual(lbp.vestingStartTime.toString(), "18446744073709551615");
-        assert.deepEqual(lbp.cliffDuration.toNumber(), lbpInitalizeData.cliffDuration.toNumber());
-        assert.deepEqual(lbp.vestingDuration.toNumber(), lbpInitalizeData.vestingDuration.toNumber());
+        assert.deepEqual(Object.keys(lbp.vestingSchedule), ["linear"]);
+        assert.deepEqual(lbp.raisedTokenLpDistribution, lbpInitalizeData.raisedTokenLpDistribution);
+        assert.deepEqual(Object.keys(lbp.treasuryRelease), ["vesting"]);
+        assert.deepEqual(lbp.governance.quorumBps, lbpInitalizeData.governance.quorumBps);
+        assert.deepEqual(lbp.governance.thresholdBps, lbpInitalizeData.governance.thresholdBps);
+        assert.deepEqual(lbp.claimDeadline.toNumber(), lbpInitalizeData.claimDeadline.toNumber());
         assert.deepEqual(lbp.bump, lbpPda[1]);
     });
 });
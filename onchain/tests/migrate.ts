@@ -0,0 +1,141 @@
+import { Context } from "./helpers/context"
+import * as assert from "assert";
+import {BN} from "@coral-xyz/anchor";
+import {PublicKey} from "@solana/web3.js";
+
+describe("Migrate", () => {
+    let ctx: Context
+
+    // Loaded from the fixtures with the legacy layouts, the vaults of the first lbp are already migrated
+    const legacyLbpUid = 1_000
+    const legacyLbpWithLegacyVaultsUid = 1_001
+    const legacyPositionMint = new PublicKey(Buffer.alloc(32, 3))
+    // The vaults of the first lbp, holding the deposit of the project
+    const legacyLbpLaunchedTokenVault = new PublicKey("7cYg7WjepQtAfC7X573WcxSqNYqwv3n2HYuFgnp92xn1")
+    const legacyLbpRaisedTokenVault = new PublicKey("3SpYwPfNAZufSg11X64o6TzomPDfw7AdLB43otaK7SJ2")
+
+    function lbpAddress(uid: number): PublicKey {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("lbp"), new BN(uid).toArrayLike(Buffer, "le", 8)],
+            ctx.program.programId
+        )[0]
+    }
+
+    function migrateLbp(uid: number) {
+        return ctx.program.methods
+            .migrateLbp()
+            .accountsPartial({
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null,
+                config: ctx.config,
+                lbp: lbpAddress(uid),
+                lbpLaunchedTokenAta: legacyLbpLaunchedTokenVault,
+                lbpRaisedTokenAta: legacyLbpRaisedTokenVault
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+    }
+
+    function legacyPositionAddress(): PublicKey {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("position"), lbpAddress(legacyLbpUid).toBuffer(), legacyPositionMint.toBuffer()],
+            ctx.program.programId
+        )[0]
+    }
+
+    function migratePosition() {
+        return ctx.program.methods
+            .migratePosition()
+            .accountsPartial({
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null,
+                config: ctx.config,
+                lbp: lbpAddress(legacyLbpUid),
+                position: legacyPositionAddress()
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+    }
+
+    before('Init context', async function () {
+        ctx = new Context()
+        await ctx.init()
+    })
+
+    it("New accounts are created with the current layout", async () => {
+        const config = await ctx.program.account.config.fetch(ctx.config);
+        assert.equal(config.version, 1)
+
+        const lbp = await ctx.program.account.lbp.fetch(ctx.fundCollectionPhaseLbp);
+        assert.equal(lbp.version, 1)
+    });
+
+    it("Accounts with the current layout cannot be migrated", async () => {
+        await assert.rejects(
+            ctx.program.methods
+                .migrateConfig()
+                .accountsPartial({
+                    adminAuthority: ctx.adminAuthority.publicKey,
+                    config: ctx.config
+                })
+                .signers([ctx.adminAuthority])
+                .rpc()
+        )
+
+        await assert.rejects(
+            ctx.program.methods
+                .migrateLbp()
+                .accountsPartial({
+                    adminAuthority: ctx.adminAuthority.publicKey,
+                    adminProposal: null,
+                    config: ctx.config,
+                    lbp: ctx.fundCollectionPhaseLbp,
+                    lbpLaunchedTokenAta: legacyLbpLaunchedTokenVault,
+                    lbpRaisedTokenAta: legacyLbpRaisedTokenVault
+                })
+                .signers([ctx.adminAuthority])
+                .rpc()
+        )
+    });
+
+    it("A legacy lbp cannot be migrated before its vaults", async () => {
+        await assert.rejects(migrateLbp(legacyLbpWithLegacyVaultsUid))
+    });
+
+    it("A legacy lbp is upgraded to the current layout", async () => {
+        await migrateLbp(legacyLbpUid)
+
+        const lbp = await ctx.program.account.lbp.fetch(lbpAddress(legacyLbpUid));
+        assert.equal(lbp.version, 1)
+        assert.equal(lbp.uid.toNumber(), legacyLbpUid)
+        assert.deepEqual(lbp.project, new PublicKey(Buffer.alloc(32, 7)))
+        assert.deepEqual(lbp.payer, ctx.adminAuthority.publicKey)
+        assert.equal(lbp.launchedTokenCap.toNumber(), 1_000_000)
+        assert.equal(lbp.raisedTokenMinCap.toNumber(), 500_000)
+        assert.equal(lbp.raisedTokenLpDistribution, 100)
+        assert.deepEqual(lbp.phase, {refund: {}})
+        assert.deepEqual(lbp.poolStatus, {poolPending: {}})
+        assert.equal(lbp.vestingSchedule.linear.cliffDuration.toNumber(), 10)
+        assert.equal(lbp.vestingSchedule.linear.vestingDuration.toNumber(), 100)
+        assert.equal(lbp.positionCount, 0)
+        assert.equal(lbp.vaultCount, 2)
+        assert.equal(lbp.launchedTokenDeposited.toNumber(), 1_000_000)
+        assert.equal(lbp.raisedTokenDeposited.toNumber(), 0)
+
+        await assert.rejects(migrateLbp(legacyLbpUid))
+    });
+
+    it("A legacy position is upgraded and counted by its lbp", async () => {
+        await migratePosition()
+
+        const lbp = await ctx.program.account.lbp.fetch(lbpAddress(legacyLbpUid));
+        assert.equal(lbp.positionCount, 1)
+
+        const position = await ctx.program.account.position.fetch(legacyPositionAddress());
+        assert.equal(position.version, 1)
+        assert.equal(position.amount.toNumber(), 420)
+        assert.deepEqual(position.payer, ctx.adminAuthority.publicKey)
+
+        await assert.rejects(migratePosition())
+    });
+});
@@ -0,0 +1,199 @@
+import { Context } from "./helpers/context"
+import * as assert from "assert";
+import {BN} from "@coral-xyz/anchor";
+import {TOKEN_PROGRAM_ID} from "@coral-xyz/anchor/dist/cjs/utils/token";
+import {getAssociatedTokenAddressSync, mintTo} from "@solana/spl-token";
+import {Keypair} from "@solana/web3.js";
+
+describe("Pause", () => {
+    let ctx: Context
+    let pauserAuthority: Keypair
+
+    before('Init context', async function () {
+        ctx = new Context()
+        await ctx.init()
+
+        pauserAuthority = Keypair.generate()
+
+        await ctx.program.methods
+            .grantRole({pauser: {}}, pauserAuthority.publicKey)
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        await ctx.program.methods
+            .acceptRole({pauser: {}})
+            .accountsPartial({
+                config: ctx.config,
+                newHolder: pauserAuthority.publicKey
+            })
+            .signers([pauserAuthority])
+            .rpc()
+    })
+
+    after('Unpause and revoke the pauser role', async function () {
+        await ctx.program.methods
+            .setPaused(false, true)
+            .accountsPartial({
+                config: ctx.config,
+                pauserAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        await ctx.program.methods
+            .revokeRole({pauser: {}})
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        const config = await ctx.program.account.config.fetchNullable(ctx.config);
+
+        assert.deepEqual(config.paused, false)
+        assert.deepEqual(config.roles, [])
+    })
+
+    it("The pauser can pause all the sales", async () => {
+        await ctx.program.methods
+            .setPaused(true, true)
+            .accountsPartial({
+                config: ctx.config,
+                pauserAuthority: pauserAuthority.publicKey,
+                adminProposal: null
+            })
+            .signers([pauserAuthority])
+            .rpc()
+
+        const config = await ctx.program.account.config.fetchNullable(ctx.config);
+
+        assert.deepEqual(config.paused, true)
+        assert.deepEqual(config.refundsExemptFromPause, true)
+    });
+
+    it("The pauser can pause a single sale", async () => {
+        await ctx.program.methods
+            .setLbpPaused(true)
+            .accountsPartial({
+                config: ctx.config,
+                lbp: ctx.fundCollectionPhaseLbp,
+                pauserAuthority: pauserAuthority.publicKey,
+                adminProposal: null
+            })
+            .signers([pauserAuthority])
+            .rpc()
+
+        let lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionPhaseLbp);
+        assert.deepEqual(lbp.paused, true)
+
+        await ctx.program.methods
+            .setLbpPaused(false)
+            .accountsPartial({
+                config: ctx.config,
+                lbp: ctx.fundCollectionPhaseLbp,
+                pauserAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionPhaseLbp);
+        assert.deepEqual(lbp.paused, false)
+    });
+
+    it("A paused sale moves no grant tokens", async () => {
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionToVestingPhaseLbp);
+        const beneficiary = Keypair.generate()
+
+        const projectLaunchedTokenAta = getAssociatedTokenAddressSync(lbp.launchedTokenMint, ctx.project.publicKey)
+        await mintTo(ctx.connection, ctx.project, lbp.launchedTokenMint, projectLaunchedTokenAta, ctx.project.publicKey, 10 ** 9)
+
+        const grant = ctx.grant(ctx.fundCollectionToVestingPhaseLbp, lbp.grantCount)
+
+        const setLbpPaused = (paused: boolean) => ctx.program.methods
+            .setLbpPaused(paused)
+            .accountsPartial({
+                config: ctx.config,
+                lbp: ctx.fundCollectionToVestingPhaseLbp,
+                pauserAuthority: pauserAuthority.publicKey,
+                adminProposal: null
+            })
+            .signers([pauserAuthority])
+            .rpc()
+
+        const createGrant = () => ctx.program.methods
+            .createGrant(
+                beneficiary.publicKey,
+                new BN(10 ** 9),
+                {linear: {cliffDuration: new BN(365 * 24 * 3600), vestingDuration: new BN(0)}},
+                true
+            )
+            .accountsPartial({
+                project: ctx.project.publicKey,
+                lbp: ctx.fundCollectionToVestingPhaseLbp,
+                grant: grant,
+                launchedTokenMint: lbp.launchedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.project])
+            .rpc()
+
+        const revokeGrant = () => ctx.program.methods
+            .revokeGrant()
+            .accountsPartial({
+                project: ctx.project.publicKey,
+                lbp: ctx.fundCollectionToVestingPhaseLbp,
+                grant: grant,
+                launchedTokenMint: lbp.launchedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.project])
+            .rpc()
+
+        // The global pause of the previous test is lifted so that only the sale is paused
+        await ctx.program.methods
+            .setPaused(false, true)
+            .accountsPartial({
+                config: ctx.config,
+                pauserAuthority: pauserAuthority.publicKey,
+                adminProposal: null
+            })
+            .signers([pauserAuthority])
+            .rpc()
+
+        await setLbpPaused(true)
+        await assert.rejects(createGrant())
+        await setLbpPaused(false)
+        await createGrant()
+
+        await setLbpPaused(true)
+        await assert.rejects(revokeGrant())
+        await setLbpPaused(false)
+        await revokeGrant()
+
+        const grantData = await ctx.program.account.grant.fetchNullable(grant);
+        assert.deepEqual(grantData.revoked, true)
+    });
+
+    it("Anyone else cannot pause", async () => {
+        await assert.rejects(
+            ctx.program.methods
+                .setPaused(true, false)
+                .accountsPartial({
+                    config: ctx.config,
+                    pauserAuthority: ctx.user.publicKey,
+                    adminProposal: null
+                })
+                .signers([ctx.user])
+                .rpc()
+        )
+    });
+});
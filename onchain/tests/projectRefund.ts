@@ -60,5 +60,22 @@ describe("Project refund", () => {
         assert.equal(launchedTokenLbpBalBefore.amount,  lbp.launchedTokenCap)
         assert.equal(launchedTokenLbpBalAfter.amount, 0)
         assert.equal(launchedTokenUserBalAfter.amount - launchedTokenUserBalBefore.amount, lbp.launchedTokenCap)
+
+        const refundedLbp = await ctx.program.account.lbp.fetchNullable(ctx.refundPhaseLbp);
+        assert.equal(refundedLbp.launchedTokenDeposited.toNumber(), 0)
+
+        await assert.rejects(
+            ctx.program.methods
+                .projectRefund()
+                .accountsPartial({
+                    project: ctx.project.publicKey,
+                    config: ctx.config,
+                    lbp: ctx.refundPhaseLbp,
+                    launchedTokenMint: lbp.launchedTokenMint,
+                    tokenProgram: TOKEN_PROGRAM_ID
+                })
+                .signers([ctx.project])
+                .rpc()
+        )
     });
 });
@@ -0,0 +1,60 @@
+import { Context } from "./helpers/context"
+import * as assert from "assert";
+import {TOKEN_PROGRAM_ID} from "@coral-xyz/anchor/dist/cjs/utils/token";
+import {getAssociatedTokenAddressSync, getAccount} from "@solana/spl-token";
+
+describe("Refund position", () => {
+    let ctx: Context
+
+    before('Init context', async function () {
+        ctx = new Context()
+        await ctx.init()
+    })
+
+    it("Anyone can refund a position to its holder", async () => {
+        let lbp = await ctx.program.account.lbp.fetchNullable(ctx.crankRefundPhaseLbp);
+        const position = await ctx.program.account.position.fetchNullable(ctx.crankRefundPhaseUserPosition)
+
+        assert.deepEqual(lbp.phase, {refund: {}})
+        assert.deepEqual(lbp.positionCount, 1)
+
+        const raisedTokenUserAtaAddress = getAssociatedTokenAddressSync(
+            lbp.raisedTokenMint,
+            ctx.user.publicKey
+        )
+
+        const raisedTokenUserBalBefore = await getAccount(
+            ctx.connection,
+            raisedTokenUserAtaAddress
+        )
+
+        // The holder does not sign, the project pays the transaction
+        await ctx.program.methods
+            .refundPosition()
+            .accountsPartial({
+                payer: ctx.project.publicKey,
+                lbp: ctx.crankRefundPhaseLbp,
+                position: ctx.crankRefundPhaseUserPosition,
+                positionPayer: position.payer,
+                holder: ctx.user.publicKey,
+                positionMint: position.mint,
+                raisedTokenMint: lbp.raisedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.project])
+            .rpc()
+
+        const raisedTokenUserBalAfter = await getAccount(
+            ctx.connection,
+            raisedTokenUserAtaAddress
+        )
+
+        assert.equal(raisedTokenUserBalAfter.amount - raisedTokenUserBalBefore.amount, ctx.amount)
+
+        const userPosition = await ctx.program.account.position.fetchNullable(ctx.crankRefundPhaseUserPosition)
+        assert.equal(userPosition, null)
+
+        lbp = await ctx.program.account.lbp.fetchNullable(ctx.crankRefundPhaseLbp);
+        assert.deepEqual(lbp.positionCount, 0)
+    });
+});
@@ -0,0 +1,192 @@
+import { Context } from "./helpers/context"
+import * as assert from "assert";
+import {BN} from "@coral-xyz/anchor";
+import {Keypair, PublicKey} from "@solana/web3.js";
+import * as anchor from "@coral-xyz/anchor";
+import {sha256} from "js-sha256";
+
+describe("Roles", () => {
+    let ctx: Context
+    let feeManager: Keypair
+
+    before('Init context', async function () {
+        ctx = new Context()
+        await ctx.init()
+
+        feeManager = Keypair.generate()
+    })
+
+    after('Set old fees back', async function () {
+        await ctx.program.methods
+            .setPlatformFees(0, 0, ctx.adminAuthority.publicKey)
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        const config = await ctx.program.account.config.fetchNullable(ctx.config);
+
+        assert.equal(config.raisedTokenFeeBps, 0)
+        assert.equal(config.launchedTokenFeeBps, 0)
+    })
+
+    function setPlatformFees(raisedTokenFeeBps: number, launchedTokenFeeBps: number, adminProposal: PublicKey = null) {
+        return ctx.program.methods
+            .setPlatformFees(raisedTokenFeeBps, launchedTokenFeeBps, ctx.adminAuthority.publicKey)
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: feeManager.publicKey,
+                adminProposal: adminProposal
+            })
+            .signers([feeManager])
+            .rpc()
+    }
+
+    async function createAdminProposal(action: any, argsHash: number[]): Promise<PublicKey> {
+        const config = await ctx.program.account.config.fetchNullable(ctx.config);
+
+        const adminProposal = PublicKey.findProgramAddressSync(
+            [
+                anchor.utils.bytes.utf8.encode("admin_proposal"),
+                new BN(config.adminProposalCount).toArrayLike(Buffer, "le", 8),
+            ],
+            ctx.program.programId
+        )[0]
+
+        await ctx.program.methods
+            .createAdminProposal(action, PublicKey.default, argsHash)
+            .accountsPartial({
+                config: ctx.config,
+                member: ctx.adminAuthority.publicKey,
+                adminProposal: adminProposal
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        return adminProposal
+    }
+
+    function platformFeesArgsHash(raisedTokenFeeBps: number, launchedTokenFeeBps: number): number[] {
+        return sha256.digest(Buffer.concat([
+            new BN(raisedTokenFeeBps).toArrayLike(Buffer, "le", 2),
+            new BN(launchedTokenFeeBps).toArrayLike(Buffer, "le", 2),
+            ctx.adminAuthority.publicKey.toBuffer(),
+        ]))
+    }
+
+    it("A granted role only takes effect once accepted by its holder", async () => {
+        await ctx.program.methods
+            .grantRole({feeManager: {}}, feeManager.publicKey)
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        let config = await ctx.program.account.config.fetchNullable(ctx.config);
+        assert.deepEqual(config.roles, [{role: {feeManager: {}}, holder: null, pendingHolder: feeManager.publicKey}])
+
+        await assert.rejects(setPlatformFees(100, 50))
+
+        // Only the pending holder can accept the role
+        await assert.rejects(
+            ctx.program.methods
+                .acceptRole({feeManager: {}})
+                .accountsPartial({
+                    config: ctx.config,
+                    newHolder: ctx.user.publicKey
+                })
+                .signers([ctx.user])
+                .rpc()
+        )
+
+        await ctx.program.methods
+            .acceptRole({feeManager: {}})
+            .accountsPartial({
+                config: ctx.config,
+                newHolder: feeManager.publicKey
+            })
+            .signers([feeManager])
+            .rpc()
+
+        config = await ctx.program.account.config.fetchNullable(ctx.config);
+        assert.deepEqual(config.roles, [{role: {feeManager: {}}, holder: feeManager.publicKey, pendingHolder: null}])
+    });
+
+    it("The holder of the role can act without the admin", async () => {
+        await setPlatformFees(100, 50)
+
+        const config = await ctx.program.account.config.fetchNullable(ctx.config);
+        assert.equal(config.raisedTokenFeeBps, 100)
+        assert.equal(config.launchedTokenFeeBps, 50)
+    });
+
+    it("The holder of the role goes through the timelock of the fees", async () => {
+        await ctx.program.methods
+            .setAdminTimelockDelay(new BN(2))
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        await assert.rejects(setPlatformFees(200, 100))
+
+        const adminProposal = await createAdminProposal({setPlatformFees: {}}, platformFeesArgsHash(200, 100))
+
+        await assert.rejects(setPlatformFees(200, 100, adminProposal))
+
+        await new Promise(resolve => setTimeout(resolve, 3_000))
+
+        await setPlatformFees(200, 100, adminProposal)
+
+        let config = await ctx.program.account.config.fetchNullable(ctx.config);
+        assert.equal(config.raisedTokenFeeBps, 200)
+        assert.equal(config.launchedTokenFeeBps, 100)
+
+        // Remove the timelock for the next tests
+        const delayProposal = await createAdminProposal(
+            {setAdminTimelockDelay: {}},
+            sha256.digest(new BN(0).toArrayLike(Buffer, "le", 8))
+        )
+
+        await new Promise(resolve => setTimeout(resolve, 3_000))
+
+        await ctx.program.methods
+            .setAdminTimelockDelay(new BN(0))
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: delayProposal
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        config = await ctx.program.account.config.fetchNullable(ctx.config);
+        assert.deepEqual(config.adminTimelockDelay.toNumber(), 0)
+    });
+
+    it("A revoked holder can no longer act", async () => {
+        await ctx.program.methods
+            .revokeRole({feeManager: {}})
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        const config = await ctx.program.account.config.fetchNullable(ctx.config);
+        assert.deepEqual(config.roles, [])
+
+        await assert.rejects(setPlatformFees(100, 50))
+    });
+});
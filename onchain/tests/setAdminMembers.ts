@@ -0,0 +1,203 @@
+import { Context } from "./helpers/context"
+import * as assert from "assert";
+import {BN} from "@coral-xyz/anchor";
+import {PublicKey} from "@solana/web3.js";
+import * as anchor from "@coral-xyz/anchor";
+import {sha256} from "js-sha256";
+
+// The borsh serialization of the (Vec<Pubkey>, u8) arguments of set_admin_members
+function adminMembersArgsHash(members: PublicKey[], threshold: number): number[] {
+    const len = Buffer.alloc(4)
+    len.writeUInt32LE(members.length)
+    return sha256.digest(Buffer.concat([len, ...members.map(member => member.toBuffer()), Buffer.from([threshold])]))
+}
+
+describe("Set admin members", () => {
+    let ctx: Context
+
+    before('Init context', async function () {
+        ctx = new Context()
+        await ctx.init()
+    })
+
+    it("The admin can enable the admin multisig", async () => {
+        await ctx.program.methods
+            .setAdminMembers([ctx.adminAuthority.publicKey, ctx.user.publicKey], 2)
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        const config = await ctx.program.account.config.fetchNullable(ctx.config);
+
+        assert.deepEqual(config.adminMembers, [ctx.adminAuthority.publicKey, ctx.user.publicKey])
+        assert.deepEqual(config.adminThreshold, 2)
+    });
+
+    it("A sensitive action cannot be executed without an approved proposal", async () => {
+        await assert.rejects(
+            ctx.program.methods
+                .setWhitelistAuthority(ctx.whitelistAuthority.publicKey)
+                .accountsPartial({
+                    config: ctx.config,
+                    adminAuthority: ctx.adminAuthority.publicKey,
+                    adminProposal: null
+                })
+                .signers([ctx.adminAuthority])
+                .rpc()
+        )
+    });
+
+    it("A single member cannot pause the platform without an approved proposal", async () => {
+        await assert.rejects(
+            ctx.program.methods
+                .setPaused(true, true)
+                .accountsPartial({
+                    config: ctx.config,
+                    pauserAuthority: ctx.adminAuthority.publicKey,
+                    adminProposal: null
+                })
+                .signers([ctx.adminAuthority])
+                .rpc()
+        )
+    });
+
+    it("The proposer can withdraw its proposal while the other members must reach the threshold", async () => {
+        const config = await ctx.program.account.config.fetchNullable(ctx.config);
+
+        const adminProposal = PublicKey.findProgramAddressSync(
+            [
+                anchor.utils.bytes.utf8.encode("admin_proposal"),
+                new BN(config.adminProposalCount).toArrayLike(Buffer, "le", 8),
+            ],
+            ctx.program.programId
+        )[0]
+
+        await ctx.program.methods
+            .createAdminProposal({setWhitelistAuthority: {}}, PublicKey.default, sha256.digest(ctx.whitelistAuthority.publicKey.toBuffer()))
+            .accountsPartial({
+                config: ctx.config,
+                member: ctx.adminAuthority.publicKey,
+                adminProposal: adminProposal
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        await ctx.program.methods
+            .cancelAdminProposal()
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.user.publicKey,
+                adminProposal: adminProposal
+            })
+            .signers([ctx.user])
+            .rpc()
+
+        let adminProposalData = await ctx.program.account.adminProposal.fetchNullable(adminProposal);
+        assert.deepEqual(adminProposalData.cancelled, false)
+        assert.deepEqual(adminProposalData.cancellations, [ctx.user.publicKey])
+
+        await assert.rejects(
+            ctx.program.methods
+                .cancelAdminProposal()
+                .accountsPartial({
+                    config: ctx.config,
+                    adminAuthority: ctx.user.publicKey,
+                    adminProposal: adminProposal
+                })
+                .signers([ctx.user])
+                .rpc()
+        )
+
+        await ctx.program.methods
+            .cancelAdminProposal()
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: adminProposal
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        adminProposalData = await ctx.program.account.adminProposal.fetchNullable(adminProposal);
+        assert.deepEqual(adminProposalData.cancelled, true)
+
+        // A cancelled proposal can no longer be approved
+        await assert.rejects(
+            ctx.program.methods
+                .approveAdminProposal()
+                .accountsPartial({
+                    config: ctx.config,
+                    member: ctx.user.publicKey,
+                    adminProposal: adminProposal
+                })
+                .signers([ctx.user])
+                .rpc()
+        )
+    });
+
+    it("The members can disable the admin multisig once they all approved it", async () => {
+        let config = await ctx.program.account.config.fetchNullable(ctx.config);
+
+        const adminProposal = PublicKey.findProgramAddressSync(
+            [
+                anchor.utils.bytes.utf8.encode("admin_proposal"),
+                new BN(config.adminProposalCount).toArrayLike(Buffer, "le", 8),
+            ],
+            ctx.program.programId
+        )[0]
+
+        await ctx.program.methods
+            .createAdminProposal({setAdminMembers: {}}, PublicKey.default, adminMembersArgsHash([], 0))
+            .accountsPartial({
+                config: ctx.config,
+                member: ctx.adminAuthority.publicKey,
+                adminProposal: adminProposal
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        // Only one member approved so far
+        await assert.rejects(
+            ctx.program.methods
+                .setAdminMembers([], 0)
+                .accountsPartial({
+                    config: ctx.config,
+                    adminAuthority: ctx.adminAuthority.publicKey,
+                    adminProposal: adminProposal
+                })
+                .signers([ctx.adminAuthority])
+                .rpc()
+        )
+
+        await ctx.program.methods
+            .approveAdminProposal()
+            .accountsPartial({
+                config: ctx.config,
+                member: ctx.user.publicKey,
+                adminProposal: adminProposal
+            })
+            .signers([ctx.user])
+            .rpc()
+
+        await ctx.program.methods
+            .setAdminMembers([], 0)
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: adminProposal
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        config = await ctx.program.account.config.fetchNullable(ctx.config);
+        assert.deepEqual(config.adminMembers, [])
+        assert.deepEqual(config.adminThreshold, 0)
+
+        const adminProposalData = await ctx.program.account.adminProposal.fetchNullable(adminProposal);
+        assert.deepEqual(adminProposalData.executed, true)
+    });
+});
@@ -0,0 +1,137 @@
+import { Context } from "./helpers/context"
+import * as assert from "assert";
+import {BN} from "@coral-xyz/anchor";
+import {PublicKey} from "@solana/web3.js";
+import * as anchor from "@coral-xyz/anchor";
+import {sha256} from "js-sha256";
+
+describe("Set admin timelock delay", () => {
+    let ctx: Context
+
+    before('Init context', async function () {
+        ctx = new Context()
+        await ctx.init()
+    })
+
+    async function createAdminProposal(action: any, argsHash: number[]): Promise<PublicKey> {
+        const config = await ctx.program.account.config.fetchNullable(ctx.config);
+
+        const adminProposal = PublicKey.findProgramAddressSync(
+            [
+                anchor.utils.bytes.utf8.encode("admin_proposal"),
+                new BN(config.adminProposalCount).toArrayLike(Buffer, "le", 8),
+            ],
+            ctx.program.programId
+        )[0]
+
+        await ctx.program.methods
+            .createAdminProposal(action, PublicKey.default, argsHash)
+            .accountsPartial({
+                config: ctx.config,
+                member: ctx.adminAuthority.publicKey,
+                adminProposal: adminProposal
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        return adminProposal
+    }
+
+    it("The admin can set a timelock delay", async () => {
+        await ctx.program.methods
+            .setAdminTimelockDelay(new BN(2))
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        const config = await ctx.program.account.config.fetchNullable(ctx.config);
+        assert.deepEqual(config.adminTimelockDelay.toNumber(), 2)
+
+        await assert.rejects(
+            ctx.program.methods
+                .setWhitelistAuthority(ctx.whitelistAuthority.publicKey)
+                .accountsPartial({
+                    config: ctx.config,
+                    adminAuthority: ctx.adminAuthority.publicKey,
+                    adminProposal: null
+                })
+                .signers([ctx.adminAuthority])
+                .rpc()
+        )
+    });
+
+    it("A queued action can be cancelled during its delay", async () => {
+        const adminProposal = await createAdminProposal(
+            {setWhitelistAuthority: {}},
+            sha256.digest(ctx.whitelistAuthority.publicKey.toBuffer())
+        )
+
+        await ctx.program.methods
+            .cancelAdminProposal()
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: adminProposal
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        const adminProposalData = await ctx.program.account.adminProposal.fetchNullable(adminProposal);
+        assert.deepEqual(adminProposalData.cancelled, true)
+
+        await new Promise(resolve => setTimeout(resolve, 3_000))
+
+        await assert.rejects(
+            ctx.program.methods
+                .setWhitelistAuthority(ctx.whitelistAuthority.publicKey)
+                .accountsPartial({
+                    config: ctx.config,
+                    adminAuthority: ctx.adminAuthority.publicKey,
+                    adminProposal: adminProposal
+                })
+                .signers([ctx.adminAuthority])
+                .rpc()
+        )
+    });
+
+    it("A queued action can only be executed after its delay", async () => {
+        const adminProposal = await createAdminProposal(
+            {setAdminTimelockDelay: {}},
+            sha256.digest(new BN(0).toArrayLike(Buffer, "le", 8))
+        )
+
+        const adminProposalData = await ctx.program.account.adminProposal.fetchNullable(adminProposal);
+        assert.notEqual(adminProposalData.eta, null)
+
+        await assert.rejects(
+            ctx.program.methods
+                .setAdminTimelockDelay(new BN(0))
+                .accountsPartial({
+                    config: ctx.config,
+                    adminAuthority: ctx.adminAuthority.publicKey,
+                    adminProposal: adminProposal
+                })
+                .signers([ctx.adminAuthority])
+                .rpc()
+        )
+
+        await new Promise(resolve => setTimeout(resolve, 3_000))
+
+        await ctx.program.methods
+            .setAdminTimelockDelay(new BN(0))
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: adminProposal
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        const config = await ctx.program.account.config.fetchNullable(ctx.config);
+        assert.deepEqual(config.adminTimelockDelay.toNumber(), 0)
+    });
+});
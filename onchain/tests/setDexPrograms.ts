@@ -0,0 +1,58 @@
+import { Context } from "./helpers/context"
+import * as assert from "assert";
+import {Keypair, PublicKey} from "@solana/web3.js";
+
+describe("Set dex programs", () => {
+    let ctx: Context
+    let orcaWhirlpoolProgramId = new PublicKey("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc")
+    let meteoraDammProgramId = new PublicKey("cpamdpZCGKUy5JxQXB4dcpGPiikHawvSWAd6mEn1sGG")
+
+    before('Init context', async function () {
+        ctx = new Context()
+        await ctx.init()
+    })
+
+    after('Set old dex programs back', async function () {
+        await ctx.program.methods
+            .setDexPrograms(ctx.raydiumCpmmProgramId, orcaWhirlpoolProgramId, meteoraDammProgramId)
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.adminAuthority.publicKey
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        const config = await ctx.program.account.config.fetchNullable(ctx.config);
+
+        assert.deepEqual(config.raydiumCpSwapProgram, ctx.raydiumCpmmProgramId)
+    })
+
+    it("The dex programs default to the mainnet program ids", async () => {
+        const config = await ctx.program.account.config.fetchNullable(ctx.config);
+
+        assert.deepEqual(config.raydiumCpSwapProgram, ctx.raydiumCpmmProgramId)
+        assert.deepEqual(config.orcaWhirlpoolProgram, orcaWhirlpoolProgramId)
+        assert.deepEqual(config.meteoraDammProgram, meteoraDammProgramId)
+    });
+
+    it("It can set the dex programs", async () => {
+        const raydiumCpSwapProgram = Keypair.generate().publicKey
+        const orcaWhirlpoolProgram = Keypair.generate().publicKey
+        const meteoraDammProgram = Keypair.generate().publicKey
+
+        await ctx.program.methods
+            .setDexPrograms(raydiumCpSwapProgram, orcaWhirlpoolProgram, meteoraDammProgram)
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.adminAuthority.publicKey
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        const config = await ctx.program.account.config.fetchNullable(ctx.config);
+
+        assert.deepEqual(config.raydiumCpSwapProgram, raydiumCpSwapProgram)
+        assert.deepEqual(config.orcaWhirlpoolProgram, orcaWhirlpoolProgram)
+        assert.deepEqual(config.meteoraDammProgram, meteoraDammProgram)
+    });
+});
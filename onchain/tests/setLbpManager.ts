@@ -0,0 +1,91 @@
+import { Context } from "./helpers/context"
+import * as assert from "assert";
+import {Keypair} from "@solana/web3.js";
+
+describe("Set lbp manager", () => {
+    let ctx: Context
+    let partnerWhitelistAuthority: Keypair
+
+    before('Init context', async function () {
+        ctx = new Context()
+        await ctx.init()
+
+        partnerWhitelistAuthority = Keypair.generate()
+    })
+
+    it("The admin can delegate the management of an lbp", async () => {
+        await ctx.program.methods
+            .setLbpManager(ctx.project.publicKey)
+            .accountsPartial({
+                config: ctx.config,
+                lbp: ctx.fundCollectionPhaseLbp,
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionPhaseLbp);
+        assert.deepEqual(lbp.manager, ctx.project.publicKey)
+    });
+
+    it("The manager can override the whitelist authority of its lbp", async () => {
+        await ctx.program.methods
+            .setLbpWhitelistAuthority(partnerWhitelistAuthority.publicKey)
+            .accountsPartial({
+                config: ctx.config,
+                lbp: ctx.fundCollectionPhaseLbp,
+                adminAuthority: ctx.project.publicKey,
+                adminProposal: null
+            })
+            .signers([ctx.project])
+            .rpc()
+
+        let lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionPhaseLbp);
+        assert.deepEqual(lbp.whitelistAuthority, partnerWhitelistAuthority.publicKey)
+
+        // Back to the whitelist authority of the config
+        await ctx.program.methods
+            .setLbpWhitelistAuthority(null)
+            .accountsPartial({
+                config: ctx.config,
+                lbp: ctx.fundCollectionPhaseLbp,
+                adminAuthority: ctx.project.publicKey,
+                adminProposal: null
+            })
+            .signers([ctx.project])
+            .rpc()
+
+        lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionPhaseLbp);
+        assert.deepEqual(lbp.whitelistAuthority, null)
+    });
+
+    it("The manager cannot appoint another manager", async () => {
+        await assert.rejects(
+            ctx.program.methods
+                .setLbpManager(ctx.user.publicKey)
+                .accountsPartial({
+                    config: ctx.config,
+                    lbp: ctx.fundCollectionPhaseLbp,
+                    adminAuthority: ctx.project.publicKey,
+                    adminProposal: null
+                })
+                .signers([ctx.project])
+                .rpc()
+        )
+
+        await ctx.program.methods
+            .setLbpManager(null)
+            .accountsPartial({
+                config: ctx.config,
+                lbp: ctx.fundCollectionPhaseLbp,
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionPhaseLbp);
+        assert.deepEqual(lbp.manager, null)
+    });
+});
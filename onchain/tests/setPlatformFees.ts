@@ -0,0 +1,68 @@
+import { Context } from "./helpers/context"
+import * as assert from "assert";
+import {Keypair} from "@solana/web3.js";
+
+describe("Set platform fees", () => {
+    let ctx: Context
+    let feeTreasury: Keypair
+
+    before('Init context', async function () {
+        ctx = new Context()
+        await ctx.init()
+
+        feeTreasury = Keypair.generate()
+    })
+
+    after('Set old fees back', async function () {
+        await ctx.program.methods
+            .setPlatformFees(0, 0, ctx.adminAuthority.publicKey)
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.adminAuthority.publicKey
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        const config = await ctx.program.account.config.fetchNullable(ctx.config);
+
+        assert.equal(config.raisedTokenFeeBps, 0)
+        assert.equal(config.launchedTokenFeeBps, 0)
+        assert.deepEqual(config.feeTreasury, ctx.adminAuthority.publicKey)
+    })
+
+    it("It can set the platform fees", async () => {
+        let config = await ctx.program.account.config.fetchNullable(ctx.config);
+
+        assert.equal(config.raisedTokenFeeBps, 0)
+        assert.equal(config.launchedTokenFeeBps, 0)
+        assert.deepEqual(config.feeTreasury, ctx.adminAuthority.publicKey)
+
+        await ctx.program.methods
+            .setPlatformFees(250, 100, feeTreasury.publicKey)
+            .accountsPartial({
+                config: ctx.config,
+                adminAuthority: ctx.adminAuthority.publicKey
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+
+        config = await ctx.program.account.config.fetchNullable(ctx.config);
+
+        assert.equal(config.raisedTokenFeeBps, 250)
+        assert.equal(config.launchedTokenFeeBps, 100)
+        assert.deepEqual(config.feeTreasury, feeTreasury.publicKey)
+    });
+
+    it("It cannot set a fee above the maximum", async () => {
+        await assert.rejects(
+            ctx.program.methods
+                .setPlatformFees(1_001, 0, feeTreasury.publicKey)
+                .accountsPartial({
+                    config: ctx.config,
+                    adminAuthority: ctx.adminAuthority.publicKey
+                })
+                .signers([ctx.adminAuthority])
+                .rpc()
+        )
+    });
+});
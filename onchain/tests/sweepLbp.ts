@@ -0,0 +1,109 @@
+import { Context } from "./helpers/context"
+import * as assert from "assert";
+import {TOKEN_PROGRAM_ID} from "@coral-xyz/anchor/dist/cjs/utils/token";
+import {getAccount, getAssociatedTokenAddressSync, transfer} from "@solana/spl-token";
+
+describe("Sweep lbp", () => {
+    let ctx: Context
+
+    before('Init context', async function () {
+        ctx = new Context()
+        await ctx.init()
+    })
+
+    async function sweepLbp() {
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.sweepLbp);
+
+        await ctx.program.methods
+            .sweepLbp({project: {}})
+            .accountsPartial({
+                authority: ctx.adminAuthority.publicKey,
+                adminProposal: null,
+                lbp: ctx.sweepLbp,
+                destination: ctx.project.publicKey,
+                launchedTokenMint: lbp.launchedTokenMint,
+                raisedTokenMint: lbp.raisedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+    }
+
+    it("The sweep of a failed sale leaves the refunds in the vaults", async () => {
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.sweepLbp);
+
+        assert.deepEqual(lbp.phase, {refund: {}})
+
+        // Only the tokens sent directly to the vaults are swept
+        await transfer(
+            ctx.connection,
+            ctx.project,
+            getAssociatedTokenAddressSync(lbp.launchedTokenMint, ctx.project.publicKey),
+            lbp.launchedTokenAta,
+            ctx.project,
+            500
+        )
+        await transfer(
+            ctx.connection,
+            ctx.user,
+            getAssociatedTokenAddressSync(lbp.raisedTokenMint, ctx.user.publicKey),
+            lbp.raisedTokenAta,
+            ctx.user,
+            1_000
+        )
+
+        await sweepLbp()
+
+        const launchedTokenLbpBal = await getAccount(ctx.connection, lbp.launchedTokenAta)
+        const raisedTokenLbpBal = await getAccount(ctx.connection, lbp.raisedTokenAta)
+
+        assert.equal(launchedTokenLbpBal.amount, BigInt(lbp.launchedTokenDeposited.toNumber()))
+        assert.equal(raisedTokenLbpBal.amount, BigInt(lbp.raisedTokenDeposited.toNumber()))
+
+        // Nothing is left to sweep
+        await assert.rejects(sweepLbp())
+    });
+
+    it("The position and the project can still be refunded after the sweep", async () => {
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.sweepLbp);
+        const position = await ctx.program.account.position.fetchNullable(ctx.sweepUserPosition)
+
+        await ctx.program.methods
+            .userRefund()
+            .accountsPartial({
+                user: ctx.user.publicKey,
+                config: ctx.config,
+                lbp: ctx.sweepLbp,
+                payer: position.payer,
+                positionMint: position.mint,
+                position: ctx.sweepUserPosition,
+                userPositionAta: getAssociatedTokenAddressSync(position.mint, ctx.user.publicKey),
+                raisedTokenMint: lbp.raisedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.user])
+            .rpc()
+
+        await ctx.program.methods
+            .projectRefund()
+            .accountsPartial({
+                project: ctx.project.publicKey,
+                config: ctx.config,
+                lbp: ctx.sweepLbp,
+                launchedTokenMint: lbp.launchedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.project])
+            .rpc()
+
+        const refundedLbp = await ctx.program.account.lbp.fetchNullable(ctx.sweepLbp);
+        assert.equal(refundedLbp.raisedTokenDeposited.toNumber(), 0)
+        assert.equal(refundedLbp.launchedTokenDeposited.toNumber(), 0)
+        assert.equal(refundedLbp.positionCount, 0)
+
+        const launchedTokenLbpBal = await getAccount(ctx.connection, lbp.launchedTokenAta)
+        const raisedTokenLbpBal = await getAccount(ctx.connection, lbp.raisedTokenAta)
+        assert.equal(launchedTokenLbpBal.amount, BigInt(0))
+        assert.equal(raisedTokenLbpBal.amount, BigInt(0))
+    });
+});
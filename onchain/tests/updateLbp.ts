@@ -0,0 +1,94 @@
+import { Context } from "./helpers/context"
+import * as assert from "assert";
+import {BN} from "@coral-xyz/anchor";
+import {getAssociatedTokenAddressSync, transfer} from "@solana/spl-token";
+
+describe("Update lbp", () => {
+    let ctx: Context
+
+    before('Init context', async function () {
+        ctx = new Context()
+        await ctx.init()
+    })
+
+    function lbpParameters(raisedTokenMinCap: BN, raisedTokenMaxCap: BN) {
+        return {
+            launchedTokenLpDistribution: 50,
+            launchedTokenCap: new BN(2_000_000),
+
+            raisedTokenMinCap: raisedTokenMinCap,
+            raisedTokenMaxCap: raisedTokenMaxCap,
+            raisedTokenLpDistribution: 70,
+
+            dex: {raydiumCpSwap: {}},
+            poolOpenTime: new BN(0),
+            lpLockPolicy: {burn: {}},
+            tradingFeeSplit: {projectBps: 5_000, treasuryBps: 5_000},
+
+            vestingSchedule: {linear: {cliffDuration: new BN(0), vestingDuration: new BN(100)}},
+
+            treasuryRelease: {milestones: {trancheCount: 2}},
+            governance: {votingDuration: new BN(10), quorumBps: 1_000, thresholdBps: 5_000},
+            claimDeadline: new BN(4_102_444_800),
+        }
+    }
+
+    async function updateLbp(lbp, parameters) {
+        await ctx.program.methods
+            .updateLbp(parameters)
+            .accountsPartial({
+                config: ctx.config,
+                lbp: lbp,
+                adminAuthority: ctx.adminAuthority.publicKey,
+                adminProposal: null
+            })
+            .signers([ctx.adminAuthority])
+            .rpc()
+    }
+
+    it("Tokens sent directly to the vaults do not count as a deposit", async () => {
+        const lbp = await ctx.program.account.lbp.fetch(ctx.updatableLbp);
+
+        await transfer(
+            ctx.connection,
+            ctx.project,
+            getAssociatedTokenAddressSync(lbp.launchedTokenMint, ctx.project.publicKey),
+            lbp.launchedTokenAta,
+            ctx.project,
+            1
+        )
+
+        const updatedLbp = await ctx.program.account.lbp.fetch(ctx.updatableLbp);
+        assert.equal(updatedLbp.launchedTokenDeposited.toNumber(), 0)
+        assert.equal(updatedLbp.raisedTokenDeposited.toNumber(), 0)
+    });
+
+    it("The admin can update an lbp before its first deposit", async () => {
+        await updateLbp(ctx.updatableLbp, lbpParameters(new BN(800_000), new BN(3_000_000)))
+
+        const lbp = await ctx.program.account.lbp.fetch(ctx.updatableLbp);
+        assert.equal(lbp.launchedTokenLpDistribution, 50)
+        assert.equal(lbp.launchedTokenCap.toNumber(), 2_000_000)
+        assert.equal(lbp.raisedTokenMinCap.toNumber(), 800_000)
+        assert.equal(lbp.raisedTokenMaxCap.toNumber(), 3_000_000)
+        assert.equal(lbp.raisedTokenLpDistribution, 70)
+        assert.deepEqual(lbp.lpLockPolicy, {burn: {}})
+        assert.deepEqual(lbp.treasuryRelease, {milestones: {trancheCount: 2}})
+    });
+
+    it("The parameters are validated", async () => {
+        await assert.rejects(
+            updateLbp(ctx.updatableLbp, lbpParameters(new BN(3_000_000), new BN(800_000)))
+        )
+    });
+
+    it("An lbp cannot be updated once it received a deposit", async () => {
+        const lbp = await ctx.program.account.lbp.fetch(ctx.fundCollectionToVestingPhaseLbp);
+        assert.equal(lbp.launchedTokenDeposited.toNumber(), lbp.launchedTokenCap.toNumber())
+        assert.equal(lbp.raisedTokenDeposited.toNumber(), ctx.raisedTokenMinCap.toNumber())
+
+        await assert.rejects(
+            updateLbp(ctx.fundCollectionToVestingPhaseLbp, lbpParameters(new BN(800_000), new BN(3_000_000)))
+        )
+    });
+});
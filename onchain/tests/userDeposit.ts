@@ -48,12 +48,13 @@ describe("User deposit", () => {
         assert.equal(await ctx.program.account.position.fetchNullable(userPositionPk[0]), null)
 
         await ctx.program.methods
-            .userDeposit(ctx.amount)
+            .userDeposit(ctx.amount, null)
             .accountsPartial({
                 whitelistAuthority: ctx.whitelistAuthority.publicKey,
                 user: ctx.user.publicKey,
                 config: ctx.config,
                 lbp: ctx.fundCollectionPhaseLbp,
+                referral: null,
                 positionMint: userPositionMintKp.publicKey,
                 position: userPositionPk[0],
                 userPositionAta: userPositionAta,
@@ -92,4 +93,95 @@ describe("User deposit", () => {
 
         assert.equal(userPositionMint.mintAuthority, null)
     });
+
+    it("It can deposit with a referrer", async () => {
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionPhaseLbp);
+
+        const referrer = Keypair.generate()
+
+        const userPositionMintKp = Keypair.generate()
+
+        const userPositionPk = PublicKey.findProgramAddressSync(
+            [Buffer.from("position"), ctx.fundCollectionPhaseLbp.toBuffer(), userPositionMintKp.publicKey.toBuffer()],
+            ctx.program.programId
+        );
+
+        const referralPk = PublicKey.findProgramAddressSync(
+            [Buffer.from("referral"), ctx.fundCollectionPhaseLbp.toBuffer(), referrer.publicKey.toBuffer()],
+            ctx.program.programId
+        );
+
+        const userPositionAta = getAssociatedTokenAddressSync(
+            userPositionMintKp.publicKey,
+            ctx.user.publicKey,
+        )
+
+        assert.equal(await ctx.program.account.referral.fetchNullable(referralPk[0]), null)
+
+        await ctx.program.methods
+            .userDeposit(ctx.amount, referrer.publicKey)
+            .accountsPartial({
+                whitelistAuthority: ctx.whitelistAuthority.publicKey,
+                user: ctx.user.publicKey,
+                config: ctx.config,
+                lbp: ctx.fundCollectionPhaseLbp,
+                referral: referralPk[0],
+                positionMint: userPositionMintKp.publicKey,
+                position: userPositionPk[0],
+                userPositionAta: userPositionAta,
+                // @ts-ignore
+                raisedTokenMint: lbp.raisedTokenMint,
+                tokenProgram: TOKEN_PROGRAM_ID
+            })
+            .signers([ctx.whitelistAuthority, ctx.user, userPositionMintKp])
+            .rpc()
+
+        const referral = await ctx.program.account.referral.fetchNullable(referralPk[0])
+
+        assert.deepEqual(referral.lbp, ctx.fundCollectionPhaseLbp)
+        assert.deepEqual(referral.referrer, referrer.publicKey)
+        assert.equal(referral.amount.toNumber(), ctx.amount.toNumber())
+        assert.equal(referral.claimed, false)
+        assert.equal(referral.bump, referralPk[1])
+
+        const lbpAfter = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionPhaseLbp);
+
+        assert.equal(lbpAfter.referredTokenCap.toNumber() - lbp.referredTokenCap.toNumber(), ctx.amount.toNumber())
+    });
+
+    it("It cannot refer itself", async () => {
+        const lbp = await ctx.program.account.lbp.fetchNullable(ctx.fundCollectionPhaseLbp);
+
+        const userPositionMintKp = Keypair.generate()
+
+        const userPositionPk = PublicKey.findProgramAddressSync(
+            [Buffer.from("position"), ctx.fundCollectionPhaseLbp.toBuffer(), userPositionMintKp.publicKey.toBuffer()],
+            ctx.program.programId
+        );
+
+        const referralPk = PublicKey.findProgramAddressSync(
+            [Buffer.from("referral"), ctx.fundCollectionPhaseLbp.toBuffer(), ctx.user.publicKey.toBuffer()],
+            ctx.program.programId
+        );
+
+        await assert.rejects(
+            ctx.program.methods
+                .userDeposit(ctx.amount, ctx.user.publicKey)
+                .accountsPartial({
+                    whitelistAuthority: ctx.whitelistAuthority.publicKey,
+                    user: ctx.user.publicKey,
+                    config: ctx.config,
+                    lbp: ctx.fundCollectionPhaseLbp,
+                    referral: referralPk[0],
+                    positionMint: userPositionMintKp.publicKey,
+                    position: userPositionPk[0],
+                    userPositionAta: getAssociatedTokenAddressSync(userPositionMintKp.publicKey, ctx.user.publicKey),
+                    // @ts-ignore
+                    raisedTokenMint: lbp.raisedTokenMint,
+                    tokenProgram: TOKEN_PROGRAM_ID
+                })
+                .signers([ctx.whitelistAuthority, ctx.user, userPositionMintKp])
+                .rpc()
+        )
+    });
 });
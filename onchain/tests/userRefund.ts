@@ -47,6 +47,7 @@ describe("User refund", () => {
                 user: ctx.user.publicKey,
                 config: ctx.config,
                 lbp: ctx.refundPhaseLbp,
+                payer: position.payer,
                 positionMint: position.mint,
                 position: ctx.refundPhaseUserPosition,
                 userPositionAta: userPositionAtaAddress,